@@ -1533,7 +1533,11 @@ pub fn layout(data_type: &DataType) -> DataTypeLayout {
         DataType::FixedSizeList(_, _) => DataTypeLayout::new_empty(), // all in child data
         DataType::LargeList(_) => DataTypeLayout::new_fixed_width(size_of::<i64>()),
         DataType::Struct(_) => DataTypeLayout::new_empty(), // all in child data,
-        DataType::RunEndEncoded(_, _) => DataTypeLayout::new_empty(), // all in child data,
+        // all in child data, and run end encoded arrays have no validity bitmap
+        DataType::RunEndEncoded(_, _) => DataTypeLayout {
+            buffers: vec![],
+            can_contain_null_mask: false,
+        },
         DataType::Union(_, mode) => {
             let type_ids = BufferSpec::FixedWidth {
                 byte_width: size_of::<i8>(),
@@ -121,10 +121,17 @@ impl FFI_ArrowArray {
     pub fn new(data: &ArrayData) -> Self {
         let data_layout = layout(data.data_type());
 
+        // The children of a sliced struct are already sliced, whereas consumers
+        // apply the parent offset to them again, so export the struct unsliced
+        let offset = match data.data_type() {
+            DataType::Struct(_) => 0,
+            _ => data.offset(),
+        };
+
         let buffers = if data_layout.can_contain_null_mask {
             // * insert the null buffer at the start
             // * make all others `Option<Buffer>`.
-            std::iter::once(align_nulls(data.offset(), data.nulls()))
+            std::iter::once(align_nulls(offset, data.nulls()))
                 .chain(data.buffers().iter().map(|b| Some(b.clone())))
                 .collect::<Vec<_>>()
         } else {
@@ -180,7 +187,7 @@ impl FFI_ArrowArray {
         Self {
             length: data.len() as i64,
             null_count: data.null_count() as i64,
-            offset: data.offset() as i64,
+            offset: offset as i64,
             n_buffers,
             n_children,
             buffers: private_data.buffers_ptr.as_mut_ptr(),
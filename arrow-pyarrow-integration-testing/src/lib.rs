@@ -21,20 +21,22 @@
 use std::sync::Arc;
 
 use arrow::array::new_empty_array;
+use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::prelude::*;
 use pyo3::wrap_pyfunction;
 
-use arrow::array::{Array, ArrayData, ArrayRef, Int64Array, make_array};
+use arrow::array::{Array, ArrayData, ArrayRef, Int64Array, StringArray, make_array};
+use arrow::buffer::Buffer;
 use arrow::compute::kernels;
 use arrow::datatypes::{DataType, Field, Schema};
 use arrow::error::ArrowError;
 use arrow::ffi_stream::ArrowArrayStreamReader;
-use arrow::pyarrow::{PyArrowConvert, PyArrowException, PyArrowType};
-use arrow::record_batch::RecordBatch;
-
-fn to_py_err(err: ArrowError) -> PyErr {
-    PyArrowException::new_err(err.to_string())
-}
+use arrow::pyarrow::{
+    from_ipc_stream, reader_into_pyarrow, to_py_err, FromPyArrow, FromPyArrowWithOptions,
+    FromPyArrowWithSchema, ImportOptions, PyArrowChunkedArray, PyArrowScalar, PyArrowTable,
+    PyArrowType, ToPyArrow, ToPyArrowCompacted, ValidationLevel,
+};
+use arrow::record_batch::{RecordBatch, RecordBatchIterator, RecordBatchReader};
 
 /// Returns `array + array` of an int64 array.
 #[pyfunction]
@@ -119,11 +121,72 @@ fn round_trip_schema(obj: PyArrowType<Schema>) -> PyResult<PyArrowType<Schema>>
     Ok(obj)
 }
 
+/// Imports a schema through `TryFrom` rather than `FromPyObject`
+#[pyfunction]
+fn schema_try_from(obj: &PyAny) -> PyResult<PyArrowType<Schema>> {
+    PyArrowType::try_from(obj)
+}
+
 #[pyfunction]
 fn round_trip_array(obj: PyArrowType<ArrayData>) -> PyResult<PyArrowType<ArrayData>> {
     Ok(obj)
 }
 
+#[pyfunction]
+fn round_trip_array_ref(obj: PyArrowType<ArrayRef>) -> PyResult<PyArrowType<ArrayRef>> {
+    Ok(obj)
+}
+
+/// Slices the array in Rust and exports the slice, optionally compacted
+#[pyfunction]
+fn slice_array(
+    array: PyArrowType<ArrayData>,
+    offset: usize,
+    length: usize,
+    compacted: bool,
+    py: Python,
+) -> PyResult<PyObject> {
+    let sliced = array.0.slice(offset, length);
+    if compacted {
+        sliced.to_pyarrow_compacted(py)
+    } else {
+        sliced.to_pyarrow(py)
+    }
+}
+
+#[pyfunction]
+fn round_trip_int64_array(obj: PyArrowType<Int64Array>) -> PyResult<PyArrowType<Int64Array>> {
+    Ok(obj)
+}
+
+#[pyfunction]
+fn round_trip_string_array(obj: PyArrowType<StringArray>) -> PyResult<PyArrowType<StringArray>> {
+    Ok(obj)
+}
+
+#[pyfunction]
+fn round_trip_chunked_array(
+    obj: PyArrowType<PyArrowChunkedArray>,
+) -> PyResult<PyArrowType<PyArrowChunkedArray>> {
+    Ok(obj)
+}
+
+#[pyfunction]
+fn concat_chunked_array(obj: PyArrowType<PyArrowChunkedArray>) -> PyResult<PyArrowType<ArrayRef>> {
+    Ok(PyArrowType(obj.0.concat().map_err(to_py_err)?))
+}
+
+#[pyfunction]
+fn round_trip_buffer(obj: PyArrowType<Buffer>) -> PyResult<PyArrowType<Buffer>> {
+    Ok(obj)
+}
+
+/// Returns a buffer allocated by Rust, which is only referenced from Python.
+#[pyfunction]
+fn make_rust_buffer() -> PyResult<PyArrowType<Buffer>> {
+    Ok(PyArrowType(Buffer::from(b"allocated by arrow-rs".as_slice())))
+}
+
 #[pyfunction]
 fn round_trip_record_batch(
     obj: PyArrowType<RecordBatch>,
@@ -138,6 +201,116 @@ fn round_trip_record_batch_reader(
     Ok(obj)
 }
 
+#[pyfunction]
+fn round_trip_table(obj: PyArrowType<PyArrowTable>) -> PyResult<PyArrowType<PyArrowTable>> {
+    Ok(obj)
+}
+
+#[pyfunction]
+fn boxed_reader_roundtrip(
+    obj: PyArrowType<Box<dyn RecordBatchReader + Send>>,
+) -> PyResult<PyArrowType<Box<dyn RecordBatchReader + Send>>> {
+    Ok(obj)
+}
+
+/// Returns the given variant of `ArrowError` as a Python exception.
+#[pyfunction]
+fn raise_arrow_error(variant: &str) -> PyResult<()> {
+    let msg = "test error".to_string();
+    let err = match variant {
+        "InvalidArgumentError" => ArrowError::InvalidArgumentError(msg),
+        "ComputeError" => ArrowError::ComputeError(msg),
+        "IoError" => ArrowError::IoError(msg),
+        "NotYetImplemented" => ArrowError::NotYetImplemented(msg),
+        "SchemaError" => ArrowError::SchemaError(msg),
+        _ => ArrowError::CastError(msg),
+    };
+    Err(to_py_err(err))
+}
+
+/// Reads every batch of `reader`, raising the first error encountered.
+#[pyfunction]
+fn read_all_batches(reader: PyArrowType<ArrowArrayStreamReader>) -> PyResult<()> {
+    for batch in reader.0 {
+        batch.map_err(to_py_err)?;
+    }
+    Ok(())
+}
+
+/// Converts a scalar to the matching Rust value and back
+#[pyfunction]
+fn round_trip_scalar(value: &PyAny, py: Python) -> PyResult<PyObject> {
+    let data_type = value.getattr("type")?.str()?.to_string();
+    match data_type.as_str() {
+        "bool" => bool::from_pyarrow(value)?.to_pyarrow(py),
+        "int32" => i32::from_pyarrow(value)?.to_pyarrow(py),
+        "int64" => i64::from_pyarrow(value)?.to_pyarrow(py),
+        "float" => f32::from_pyarrow(value)?.to_pyarrow(py),
+        "double" => f64::from_pyarrow(value)?.to_pyarrow(py),
+        "string" => String::from_pyarrow(value)?.to_pyarrow(py),
+        "binary" => Vec::<u8>::from_pyarrow(value)?.to_pyarrow(py),
+        _ => Err(PyTypeError::new_err(format!("Unsupported scalar type {data_type}"))),
+    }
+}
+
+#[pyfunction]
+fn round_trip_pyarrow_scalar(
+    obj: PyArrowType<PyArrowScalar>,
+) -> PyResult<PyArrowType<PyArrowScalar>> {
+    Ok(obj)
+}
+
+/// Imports an array with the given validation level ("full", "structural" or "none")
+#[pyfunction]
+fn round_trip_array_with_validation(
+    obj: &PyAny,
+    validation: &str,
+    py: Python,
+) -> PyResult<PyObject> {
+    let validation = match validation {
+        "full" => ValidationLevel::Full,
+        "structural" => ValidationLevel::Structural,
+        "none" => ValidationLevel::None,
+        other => {
+            return Err(PyValueError::new_err(format!(
+                "Unknown validation level {other}"
+            )))
+        }
+    };
+    // Safety: the array is only exported back to pyarrow, its values are never
+    // read on the Rust side
+    let options = unsafe { ImportOptions::default().with_validation(validation) };
+    ArrayData::from_pyarrow_with_options(obj, options)?.to_pyarrow(py)
+}
+
+/// Returns a reader whose third batch fails with `message`
+#[pyfunction]
+fn make_failing_reader(message: String, py: Python) -> PyResult<PyObject> {
+    let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, false)]));
+    let batch =
+        RecordBatch::try_new(schema.clone(), vec![Arc::new(Int64Array::from(vec![1]))])
+            .map_err(to_py_err)?;
+    let batches = vec![Ok(batch.clone()), Ok(batch), Err(ArrowError::ComputeError(message))];
+    reader_into_pyarrow(py, RecordBatchIterator::new(batches, schema))
+}
+
+/// Reads every batch of an Arrow IPC stream
+#[pyfunction]
+fn read_ipc_stream(stream: &PyAny) -> PyResult<PyArrowType<Vec<RecordBatch>>> {
+    let reader = from_ipc_stream(stream)?;
+    let batches = reader.collect::<Result<_, _>>().map_err(to_py_err)?;
+    Ok(PyArrowType(batches))
+}
+
+/// Reads the batches of a stream, asking the producer for the fields of `schema`
+#[pyfunction]
+fn read_projected_batches(
+    stream: &PyAny,
+    schema: PyArrowType<Schema>,
+) -> PyResult<PyArrowType<Vec<RecordBatch>>> {
+    Ok(PyArrowType(Vec::from_pyarrow_with_schema(stream, &schema.0)?))
+}
+
 #[pymodule]
 fn arrow_pyarrow_integration_testing(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_wrapped(wrap_pyfunction!(double))?;
@@ -148,8 +321,27 @@ fn arrow_pyarrow_integration_testing(_py: Python, m: &PyModule) -> PyResult<()>
     m.add_wrapped(wrap_pyfunction!(round_trip_type))?;
     m.add_wrapped(wrap_pyfunction!(round_trip_field))?;
     m.add_wrapped(wrap_pyfunction!(round_trip_schema))?;
+    m.add_wrapped(wrap_pyfunction!(schema_try_from))?;
     m.add_wrapped(wrap_pyfunction!(round_trip_array))?;
+    m.add_wrapped(wrap_pyfunction!(round_trip_array_ref))?;
+    m.add_wrapped(wrap_pyfunction!(slice_array))?;
+    m.add_wrapped(wrap_pyfunction!(round_trip_int64_array))?;
+    m.add_wrapped(wrap_pyfunction!(round_trip_string_array))?;
+    m.add_wrapped(wrap_pyfunction!(round_trip_chunked_array))?;
+    m.add_wrapped(wrap_pyfunction!(concat_chunked_array))?;
+    m.add_wrapped(wrap_pyfunction!(round_trip_buffer))?;
+    m.add_wrapped(wrap_pyfunction!(make_rust_buffer))?;
     m.add_wrapped(wrap_pyfunction!(round_trip_record_batch))?;
     m.add_wrapped(wrap_pyfunction!(round_trip_record_batch_reader))?;
+    m.add_wrapped(wrap_pyfunction!(boxed_reader_roundtrip))?;
+    m.add_wrapped(wrap_pyfunction!(raise_arrow_error))?;
+    m.add_wrapped(wrap_pyfunction!(read_all_batches))?;
+    m.add_wrapped(wrap_pyfunction!(read_projected_batches))?;
+    m.add_wrapped(wrap_pyfunction!(read_ipc_stream))?;
+    m.add_wrapped(wrap_pyfunction!(make_failing_reader))?;
+    m.add_wrapped(wrap_pyfunction!(round_trip_scalar))?;
+    m.add_wrapped(wrap_pyfunction!(round_trip_pyarrow_scalar))?;
+    m.add_wrapped(wrap_pyfunction!(round_trip_array_with_validation))?;
+    m.add_wrapped(wrap_pyfunction!(round_trip_table))?;
     Ok(())
 }
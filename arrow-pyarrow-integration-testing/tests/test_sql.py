@@ -19,6 +19,7 @@
 import contextlib
 import datetime
 import decimal
+import gc
 import string
 
 import pytest
@@ -145,12 +146,58 @@ def test_field_metadata_roundtrip():
     assert field == pyarrow_field
     assert field.metadata == pyarrow_field.metadata
 
+class UuidType(pa.ExtensionType):
+    def __init__(self):
+        super().__init__(pa.binary(16), "arrow_rs.uuid")
+
+    def __arrow_ext_serialize__(self):
+        return b""
+
+    @classmethod
+    def __arrow_ext_deserialize__(cls, storage_type, serialized):
+        return UuidType()
+
+def test_extension_type_roundtrip():
+    # arrow-rs has no extension types, so only the storage type comes back
+    restored = rust.round_trip_type(UuidType())
+    assert restored == pa.binary(16)
+
+def test_extension_field_roundtrip():
+    pa.register_extension_type(UuidType())
+    try:
+        # the extension name travels in the field metadata
+        pyarrow_field = pa.field("test", UuidType())
+        field = rust.round_trip_field(pyarrow_field)
+        assert field == pyarrow_field
+    finally:
+        pa.unregister_extension_type("arrow_rs.uuid")
+
+def test_extension_array_roundtrip():
+    storage = pa.array([b"0123456789abcdef", None], pa.binary(16))
+    a = pa.ExtensionArray.from_storage(UuidType(), storage)
+    b = rust.round_trip_array(a)
+    assert b == storage
+
+def test_roundtrip_wrong_class_raises():
+    with pytest.raises(TypeError, match="pyarrow.DataType"):
+        rust.round_trip_type(pa.field("test", pa.int32()))
+    with pytest.raises(TypeError, match="pyarrow.Array"):
+        rust.round_trip_array(pa.int32())
+
 def test_schema_roundtrip():
     pyarrow_fields = zip(string.ascii_lowercase, _supported_pyarrow_types)
     pyarrow_schema = pa.schema(pyarrow_fields)
     schema = rust.round_trip_schema(pyarrow_schema)
     assert schema == pyarrow_schema
 
+def test_schema_try_from():
+    pyarrow_schema = pa.schema([("a", pa.int32()), ("b", pa.string())], metadata={"x": "2"})
+    schema = rust.schema_try_from(pyarrow_schema)
+    assert schema == pyarrow_schema
+    assert schema.metadata == pyarrow_schema.metadata
+
+    with pytest.raises(TypeError, match="Expected instance of pyarrow.Schema"):
+        rust.schema_try_from(42)
 
 def test_primitive_python():
     """
@@ -364,6 +411,45 @@ def test_dictionary_python():
     del a
     del b
 
+@pytest.mark.parametrize("index_type", [pa.int8(), pa.int16(), pa.int32()], ids=str)
+def test_dictionary_index_type_python(index_type):
+    a = pa.array(["a", None, "b", None, "a"], type=pa.dictionary(index_type, pa.string()))
+    b = rust.round_trip_array(a)
+    assert b.type.index_type == index_type
+    assert a == b
+
+def test_dictionary_ordered_python():
+    ordered = pa.dictionary(pa.int8(), pa.string(), ordered=True)
+    field = pa.field("dict", ordered)
+    assert rust.round_trip_field(field).type.ordered
+
+    schema = pa.schema([
+        field,
+        pa.field("unordered", pa.dictionary(pa.int16(), pa.string())),
+        pa.field("list", pa.list_(pa.field("item", ordered))),
+        pa.field("struct", pa.struct([field])),
+    ])
+    restored = rust.round_trip_schema(schema)
+    assert restored == schema
+    assert restored.field("dict").type.ordered
+    assert not restored.field("unordered").type.ordered
+    assert restored.field("list").type.value_type.ordered
+    assert restored.field("struct").type[0].type.ordered
+
+    dictionary = pa.array(["a", None, "b"], type=ordered)
+    batch = pa.record_batch(
+        [
+            dictionary,
+            pa.array(["x", "y", None], type=pa.dictionary(pa.int16(), pa.string())),
+            pa.ListArray.from_arrays([0, 1, 1, 3], dictionary, type=schema.field("list").type),
+            pa.StructArray.from_arrays([dictionary], fields=[field]),
+        ],
+        schema=schema,
+    )
+    b = rust.round_trip_record_batch(batch)
+    assert b.schema == schema
+    assert b == batch
+
 def test_dense_union_python():
     """
     Python -> Rust -> Python
@@ -393,6 +479,174 @@ def test_sparse_union_python():
     del a
     del b
 
+def test_buffer():
+    """
+    Python -> Rust -> Python
+    """
+    a = pa.py_buffer(b"arrow-rs")
+    b = rust.round_trip_buffer(a)
+
+    assert b.to_pybytes() == b"arrow-rs"
+    # the round trip must not copy the underlying memory
+    assert b.address == a.address
+    del a
+    assert b.to_pybytes() == b"arrow-rs"
+
+def test_buffer_protocol():
+    """
+    Python buffer protocol -> Rust -> Python
+    """
+    data = b"arrow-rs"
+    b = rust.round_trip_buffer(data)
+    assert b.to_pybytes() == data
+    # bytes are imported without copying
+    assert b.address == pa.py_buffer(data).address
+
+    assert rust.round_trip_buffer(bytearray(data)).to_pybytes() == data
+    assert rust.round_trip_buffer(memoryview(data)).to_pybytes() == data
+
+    # non-contiguous buffers are copied
+    assert rust.round_trip_buffer(memoryview(data)[::2]).to_pybytes() == b"arwr"
+
+    with pytest.raises(TypeError):
+        rust.round_trip_buffer("not a buffer")
+
+def test_buffer_numpy():
+    np = pytest.importorskip("numpy")
+    a = np.arange(4, dtype=np.int32)
+    b = rust.round_trip_buffer(a)
+    assert b.to_pybytes() == a.tobytes()
+    assert b.address == a.ctypes.data
+
+def test_buffer_outlives_python_source():
+    source = bytearray(b"arrow-rs")
+    b = rust.round_trip_buffer(memoryview(source))
+    del source
+    gc.collect()
+    assert b.to_pybytes() == b"arrow-rs"
+
+def test_buffer_outlives_rust_source():
+    b = rust.make_rust_buffer()
+    gc.collect()
+    assert b.to_pybytes() == b"allocated by arrow-rs"
+    # the Rust buffer is released with the last Python reference
+    c = pa.py_buffer(b)
+    del b
+    gc.collect()
+    assert c.to_pybytes() == b"allocated by arrow-rs"
+
+def test_array_ref_roundtrip():
+    a = pa.array([1, None, 3], pa.int32())
+    b = rust.round_trip_array_ref(a)
+    assert b == a
+
+_sliced_arrays = [
+    pa.array([True, None, False, True, None, True, False, False, True, None, True]),
+    pa.array(["a", None, "bc", "", "def", None, "g", "hi", "j", None, "kl"]),
+    pa.array(
+        [[1], None, [2, 3], [], [4, None], None, [5], [6, 7, 8], [], [9], None],
+        pa.list_(pa.int32()),
+    ),
+    pa.array(
+        [{"a": i, "b": str(i)} if i % 4 else None for i in range(11)],
+        pa.struct([("a", pa.int32()), ("b", pa.string())]),
+    ),
+]
+
+@pytest.mark.parametrize("array", _sliced_arrays, ids=lambda a: str(a.type))
+@pytest.mark.parametrize("compacted", [False, True])
+def test_sliced_array_export(array, compacted):
+    for offset, length in [(1, 5), (3, 7), (5, 3), (7, 4)]:
+        b = rust.slice_array(array, offset, length, compacted)
+        b.validate(full=True)
+        assert b == array.slice(offset, length)
+        assert b.to_pylist() == array.slice(offset, length).to_pylist()
+
+@pytest.mark.parametrize("compacted", [False, True])
+def test_pyarrow_sliced_struct_roundtrip(compacted):
+    a = _sliced_arrays[-1]
+    for offset, length in [(1, 3), (3, 7), (4, 5)]:
+        sliced = a.slice(offset, length)
+        b = rust.round_trip_array(sliced)
+        b.validate(full=True)
+        assert b == sliced
+        assert b.to_pylist() == sliced.to_pylist()
+
+        # slice again on the Rust side, on top of the pyarrow offset
+        b = rust.slice_array(sliced, 1, length - 1, compacted)
+        b.validate(full=True)
+        assert b == sliced.slice(1)
+
+_requires_run_end_encoded = pytest.mark.skipif(
+    not hasattr(pa, "RunEndEncodedArray"),
+    reason="pyarrow does not support run end encoded arrays",
+)
+
+@_requires_run_end_encoded
+def test_run_end_encoded_roundtrip():
+    a = pa.RunEndEncodedArray.from_arrays([2, 3, 6], ["a", None, "b"])
+    b = rust.round_trip_array(a)
+    b.validate(full=True)
+    assert b.type == a.type
+    assert b.to_pylist() == a.to_pylist()
+
+    sliced = a.slice(1, 4)
+    b = rust.round_trip_array(sliced)
+    b.validate(full=True)
+    assert b.to_pylist() == sliced.to_pylist()
+
+@_requires_run_end_encoded
+def test_run_end_encoded_sliced_export():
+    a = pa.RunEndEncodedArray.from_arrays(
+        pa.array([2, 3, 6], pa.int16()), pa.array([1.5, None, 2.5])
+    )
+    b = rust.slice_array(a, 1, 4, False)
+    b.validate(full=True)
+    assert b.to_pylist() == a.slice(1, 4).to_pylist()
+
+    with pytest.raises(pa.ArrowNotImplementedError, match="RunEndEncoded"):
+        rust.slice_array(a, 1, 4, True)
+
+def test_compacted_array_has_no_offset():
+    a = pa.array([True, None, False, True, None, True, False, False, True])
+    b = rust.slice_array(a, 3, 5, True)
+    assert b.offset == 0
+    assert b == a.slice(3, 5)
+
+def test_typed_array_roundtrip():
+    a = pa.array([1, None, 3], pa.int64())
+    b = rust.round_trip_int64_array(a)
+    assert b == a
+
+    a = pa.array(["a", None, "c"], pa.string())
+    b = rust.round_trip_string_array(a)
+    assert b == a
+
+def test_typed_array_wrong_type_raises():
+    a = pa.array(["a", None, "c"], pa.string())
+    with pytest.raises(TypeError, match="Expected array of type Int64, got Utf8"):
+        rust.round_trip_int64_array(a)
+
+def test_chunked_array_roundtrip():
+    a = pa.chunked_array([[1, 2], [], [None, 4]], pa.int64())
+    b = rust.round_trip_chunked_array(a)
+    assert b == a
+    assert b.num_chunks == 3
+
+def test_chunked_array_no_chunks_roundtrip():
+    a = pa.chunked_array([], pa.list_(pa.string()))
+    b = rust.round_trip_chunked_array(a)
+    assert b.type == pa.list_(pa.string())
+    assert b.num_chunks == 0
+
+def test_chunked_array_concat():
+    a = pa.chunked_array([["a", "b"], [None], ["d"]], pa.string())
+    b = rust.concat_chunked_array(a)
+    assert b == pa.array(["a", "b", None, "d"], pa.string())
+
+    b = rust.concat_chunked_array(pa.chunked_array([], pa.int32()))
+    assert b == pa.array([], pa.int32())
+
 def test_record_batch_reader():
     """
     Python -> Rust -> Python
@@ -408,3 +662,414 @@ def test_record_batch_reader():
     assert b.schema == schema
     got_batches = list(b)
     assert got_batches == batches
+
+def test_record_batch_reader_boxed():
+    """
+    Python -> Rust -> Python
+    """
+    schema = pa.schema([('ints', pa.list_(pa.int32()))], metadata={b'key1': b'value1'})
+    batches = [
+        pa.record_batch([[[1], [2, 42]]], schema),
+        pa.record_batch([[None, [], [5, 6]]], schema),
+    ]
+    a = pa.RecordBatchReader.from_batches(schema, batches)
+    b = rust.boxed_reader_roundtrip(a)
+
+    assert b.schema == schema
+    got_batches = list(b)
+    assert got_batches == batches
+
+def test_record_batch_metadata_roundtrip():
+    schema = pa.schema(
+        [pa.field("ints", pa.int32(), metadata={"field_key": "field_value"})],
+        metadata={"schema_key": "schema_value"},
+    )
+    batch = pa.record_batch([[1, None, 3]], schema)
+    b = rust.round_trip_record_batch(batch)
+    assert b == batch
+    assert b.schema.metadata == schema.metadata
+    assert b.schema.field("ints").metadata == schema.field("ints").metadata
+
+def test_metadata_roundtrip_all_conversions():
+    pa.register_extension_type(UuidType())
+    try:
+        child = pa.field("child", pa.int32(), metadata={"child_key": "child_value"})
+        schema = pa.schema(
+            [
+                pa.field("ints", pa.int32(), metadata={"field_key": "field_value"}),
+                pa.field("nested", pa.struct([child]), metadata={"struct_key": "struct_value"}),
+                pa.field("uuid", UuidType()),
+            ],
+            metadata={"schema_key": "schema_value"},
+        )
+        uuids = pa.ExtensionArray.from_storage(
+            UuidType(), pa.array([b"0123456789abcdef", None], pa.binary(16))
+        )
+        batch = pa.record_batch(
+            [
+                pa.array([1, None], pa.int32()),
+                pa.array([{"child": 1}, None], schema.field("nested").type),
+                uuids,
+            ],
+            schema=schema,
+        )
+
+        def check_schema(restored):
+            assert restored == schema
+            assert restored.metadata == schema.metadata
+            for name in schema.names:
+                assert restored.field(name).metadata == schema.field(name).metadata
+            assert restored.field("nested").type[0].metadata == child.metadata
+            assert restored.field("uuid").type == UuidType()
+
+        check_schema(rust.round_trip_schema(schema))
+        for name in schema.names:
+            field = rust.round_trip_field(schema.field(name))
+            assert field == schema.field(name)
+            assert field.metadata == schema.field(name).metadata
+
+        b = rust.round_trip_record_batch(batch)
+        check_schema(b.schema)
+        assert b == batch
+
+        reader = pa.RecordBatchReader.from_batches(schema, [batch])
+        b = rust.round_trip_record_batch_reader(reader)
+        check_schema(b.schema)
+        assert b.read_all() == pa.Table.from_batches([batch])
+
+        table = rust.round_trip_table(pa.Table.from_batches([batch]))
+        check_schema(table.schema)
+    finally:
+        pa.unregister_extension_type("arrow_rs.uuid")
+
+@pytest.mark.parametrize(
+    "variant,exception",
+    [
+        ("InvalidArgumentError", pa.ArrowInvalid),
+        ("ComputeError", pa.ArrowInvalid),
+        ("IoError", OSError),
+        ("NotYetImplemented", pa.ArrowNotImplementedError),
+        ("SchemaError", pa.ArrowTypeError),
+        ("CastError", pa.ArrowException),
+    ],
+)
+def test_arrow_error_to_python(variant, exception):
+    with pytest.raises(exception, match="test error"):
+        rust.raise_arrow_error(variant)
+
+@pytest.mark.parametrize(
+    "python_exception,exception",
+    [
+        (ValueError("boom"), pa.ArrowInvalid),
+        (OSError("boom"), OSError),
+        (NotImplementedError("boom"), pa.ArrowNotImplementedError),
+    ],
+)
+def test_reader_error_to_rust(python_exception, exception):
+    schema = pa.schema([("ints", pa.int32())])
+
+    def batches():
+        yield pa.record_batch([[1, 2]], schema)
+        raise python_exception
+
+    reader = pa.RecordBatchReader.from_batches(schema, batches())
+    with pytest.raises(exception, match="boom"):
+        rust.read_all_batches(reader)
+
+def test_table_roundtrip():
+    schema = pa.schema([("ints", pa.int32()), ("strs", pa.string())], metadata={"key1": "value1"})
+    table = pa.table([[1, None, 3], ["a", "b", None]], schema=schema)
+    b = rust.round_trip_table(table)
+    assert b == table
+    assert b.schema.metadata == schema.metadata
+
+def test_table_empty_roundtrip():
+    schema = pa.schema([("ints", pa.int32())])
+    table = pa.Table.from_batches([], schema=schema)
+    b = rust.round_trip_table(table)
+    assert b.num_rows == 0
+    assert b.schema == schema
+
+def test_table_no_columns_roundtrip():
+    batch = pa.RecordBatch.from_pydict({"x": [1, 2, 3]}).select([])
+    table = pa.Table.from_batches([batch])
+    b = rust.round_trip_table(table)
+    assert b.num_columns == 0
+    assert b.num_rows == 3
+
+def test_table_chunked_roundtrip():
+    # columns with different chunk layouts are aligned by Table.to_reader()
+    a = pa.chunked_array([[1, 2], [3], [4, 5, 6]], pa.int64())
+    b = pa.chunked_array([["a", "b", "c", "d", "e", "f"]], pa.string())
+    table = pa.table([a, b], names=["a", "b"])
+    restored = rust.round_trip_table(table)
+    assert restored == table
+
+# Minimal objects implementing only the Arrow PyCapsule Interface, standing in
+# for libraries other than pyarrow. They delegate to pyarrow >= 14 to create
+# the capsules.
+_requires_pycapsule = pytest.mark.skipif(
+    not hasattr(pa.Schema, "__arrow_c_schema__"),
+    reason="pyarrow does not implement the Arrow PyCapsule Interface",
+)
+
+class ArrowSchemaWrapper:
+    def __init__(self, schema):
+        self.schema = schema
+
+    def __arrow_c_schema__(self):
+        return self.schema.__arrow_c_schema__()
+
+class ArrowArrayWrapper:
+    def __init__(self, array):
+        self.array = array
+
+    def __arrow_c_array__(self, requested_schema=None):
+        return self.array.__arrow_c_array__(requested_schema)
+
+class ArrowStreamWrapper:
+    def __init__(self, reader):
+        self.reader = reader
+
+    def __arrow_c_stream__(self, requested_schema=None):
+        return self.reader.__arrow_c_stream__(requested_schema)
+
+@_requires_pycapsule
+def test_type_roundtrip_pycapsule():
+    restored = rust.round_trip_type(ArrowSchemaWrapper(pa.list_(pa.int32())))
+    assert restored == pa.list_(pa.int32())
+
+@_requires_pycapsule
+def test_field_roundtrip_pycapsule():
+    pyarrow_field = pa.field("test", pa.int32(), nullable=False, metadata={"x": "2"})
+    field = rust.round_trip_field(ArrowSchemaWrapper(pyarrow_field))
+    assert field == pyarrow_field
+    assert field.metadata == pyarrow_field.metadata
+
+@_requires_pycapsule
+def test_schema_roundtrip_pycapsule():
+    pyarrow_schema = pa.schema([("a", pa.int32()), ("b", pa.string())], metadata={"x": "2"})
+    schema = rust.round_trip_schema(ArrowSchemaWrapper(pyarrow_schema))
+    assert schema == pyarrow_schema
+    assert schema.metadata == pyarrow_schema.metadata
+
+@_requires_pycapsule
+def test_array_roundtrip_pycapsule():
+    a = pa.array([1, None, 3], pa.int64())
+    b = rust.round_trip_array(ArrowArrayWrapper(a))
+    assert b == a
+
+@_requires_pycapsule
+def test_record_batch_roundtrip_pycapsule():
+    schema = pa.schema([("ints", pa.int32())], metadata={"key1": "value1"})
+    batch = pa.record_batch([[1, 2, None]], schema)
+    b = rust.round_trip_record_batch(ArrowArrayWrapper(batch))
+    assert b == batch
+    assert b.schema.metadata == schema.metadata
+
+@_requires_pycapsule
+def test_record_batch_reader_pycapsule():
+    schema = pa.schema([("ints", pa.list_(pa.int32()))])
+    batches = [
+        pa.record_batch([[[1], [2, 42]]], schema),
+        pa.record_batch([[None, [], [5, 6]]], schema),
+    ]
+    a = pa.RecordBatchReader.from_batches(schema, batches)
+    b = rust.round_trip_record_batch_reader(ArrowStreamWrapper(a))
+
+    assert b.schema == schema
+    assert list(b) == batches
+
+@_requires_pycapsule
+def test_pycapsule_wrong_name_raises():
+    class BadSchema:
+        def __arrow_c_schema__(self):
+            return pa.array([1]).__arrow_c_array__()[1]
+
+    with pytest.raises(ValueError, match="arrow_schema"):
+        rust.round_trip_type(BadSchema())
+
+class RecordingStreamProducer:
+    """Records the schema requested from ``__arrow_c_stream__``, honouring it
+    only if ``honour_request`` is set."""
+
+    def __init__(self, table, honour_request):
+        self.table = table
+        self.honour_request = honour_request
+        self.requested_schema = None
+
+    def __arrow_c_stream__(self, requested_schema=None):
+        if requested_schema is not None:
+            self.requested_schema = pa.Schema._import_from_c_capsule(requested_schema)
+        table = self.table
+        if self.honour_request and self.requested_schema is not None:
+            table = table.select(self.requested_schema.names)
+        return table.__arrow_c_stream__()
+
+@_requires_pycapsule
+@pytest.mark.parametrize("honour_request", [True, False])
+def test_stream_projection_pycapsule(honour_request):
+    table = pa.table({"a": [1, 2, 3], "b": ["x", "y", "z"], "c": [1.0, 2.0, 3.0]})
+    producer = RecordingStreamProducer(table, honour_request)
+    schema = pa.schema([("c", pa.float64()), ("a", pa.int64())])
+
+    batches = rust.read_projected_batches(producer, schema)
+
+    assert producer.requested_schema == schema
+    assert pa.Table.from_batches(batches, schema) == table.select(["c", "a"])
+
+class LegacyStreamProducer:
+    """A producer whose ``__arrow_c_stream__`` takes no requested schema."""
+
+    def __init__(self, table):
+        self.table = table
+
+    def __arrow_c_stream__(self):
+        return self.table.__arrow_c_stream__()
+
+class FailingStreamProducer:
+    """A producer whose ``__arrow_c_stream__`` always fails with ``OSError``."""
+
+    def __init__(self):
+        self.calls = 0
+
+    def __arrow_c_stream__(self, requested_schema=None):
+        self.calls += 1
+        raise OSError("producer is broken")
+
+@_requires_pycapsule
+def test_stream_projection_falls_back_on_type_error():
+    table = pa.table({"a": [1, 2, 3], "b": ["x", "y", "z"]})
+    schema = pa.schema([("b", pa.string())])
+    batches = rust.read_projected_batches(LegacyStreamProducer(table), schema)
+    assert pa.Table.from_batches(batches, schema) == table.select(["b"])
+
+@_requires_pycapsule
+def test_stream_projection_type_mismatch_raises():
+    table = pa.table({"a": pa.array([1, 2, 3], pa.int32())})
+    schema = pa.schema([("a", pa.int64())])
+    with pytest.raises(TypeError, match="Expected field 'a' of type Int64"):
+        rust.read_projected_batches(LegacyStreamProducer(table), schema)
+
+    schema = pa.schema([pa.field("a", pa.int32(), nullable=False)])
+    with pytest.raises(TypeError, match="nullable: false"):
+        rust.read_projected_batches(LegacyStreamProducer(table), schema)
+
+def test_stream_projection_producer_error_raises():
+    producer = FailingStreamProducer()
+    schema = pa.schema([("a", pa.int64())])
+    with pytest.raises(OSError, match="producer is broken"):
+        rust.read_projected_batches(producer, schema)
+    assert producer.calls == 1
+
+def test_stream_projection_missing_field_raises():
+    table = pa.table({"a": [1, 2, 3]})
+    schema = pa.schema([("b", pa.int64())])
+    with pytest.raises(pa.ArrowTypeError, match="Unable to get field named"):
+        rust.read_projected_batches(table.to_reader(), schema)
+
+def _ipc_stream(batches):
+    sink = pa.BufferOutputStream()
+    with pa.ipc.new_stream(sink, batches[0].schema) as writer:
+        for batch in batches:
+            writer.write_batch(batch)
+    return sink.getvalue()
+
+def test_ipc_stream():
+    schema = pa.schema([("a", pa.int32()), ("b", pa.string())], metadata={"x": "2"})
+    batches = [
+        pa.record_batch([[1, 2], ["a", None]], schema),
+        pa.record_batch([[None], ["c"]], schema),
+    ]
+    buffer = _ipc_stream(batches)
+
+    assert rust.read_ipc_stream(buffer) == batches
+    assert rust.read_ipc_stream(buffer.to_pybytes()) == batches
+    assert rust.read_ipc_stream(pa.ipc.open_stream(buffer)) == batches
+    assert rust.read_ipc_stream(pa.RecordBatchReader.from_batches(schema, batches)) == batches
+
+def test_ipc_stream_invalid_raises():
+    # The bytes are read as a message length far beyond the end of the stream
+    with pytest.raises(OSError):
+        rust.read_ipc_stream(b"not an ipc stream")
+
+def test_reader_error_message():
+    reader = rust.make_failing_reader("third batch is broken")
+    assert reader.read_next_batch().num_rows == 1
+    assert reader.read_next_batch().num_rows == 1
+    with pytest.raises(pa.ArrowException, match="third batch is broken"):
+        reader.read_next_batch()
+
+@pytest.mark.parametrize(
+    "scalar",
+    [
+        pa.scalar(True),
+        pa.scalar(-3, pa.int32()),
+        pa.scalar(2**40, pa.int64()),
+        pa.scalar(1.5, pa.float32()),
+        pa.scalar(2.25, pa.float64()),
+        pa.scalar("arrow-rs"),
+        pa.scalar(b"\x00arrow"),
+    ],
+    ids=lambda s: str(s.type),
+)
+def test_scalar_roundtrip(scalar):
+    b = rust.round_trip_scalar(scalar)
+    assert b.type == scalar.type
+    assert b == scalar
+
+def test_scalar_null_raises():
+    with pytest.raises(ValueError, match="Expected non-null scalar"):
+        rust.round_trip_scalar(pa.scalar(None, pa.int32()))
+
+def test_scalar_wrong_class_raises():
+    with pytest.raises(TypeError, match="Expected instance of pyarrow.Scalar"):
+        rust.round_trip_scalar(pa.array([1], pa.int32()))
+
+@pytest.mark.parametrize(
+    "scalar",
+    [
+        pa.scalar(42, pa.int64()),
+        pa.scalar("arrow-rs"),
+        pa.scalar(None, pa.int32()),
+        pa.scalar(None, pa.struct([("a", pa.int32())])),
+        pa.scalar({"a": 1, "b": [1, None, 3]}, pa.struct([("a", pa.int32()), ("b", pa.list_(pa.int64()))])),
+        pa.scalar([1, None, 3], pa.list_(pa.int64())),
+    ],
+    ids=lambda s: f"{s.type}-{s.is_valid}",
+)
+def test_pyarrow_scalar_roundtrip(scalar):
+    b = rust.round_trip_pyarrow_scalar(scalar)
+    assert b.type == scalar.type
+    assert b.is_valid == scalar.is_valid
+    assert b == scalar
+
+def _invalid_utf8_array():
+    offsets = pa.array([0, 2], pa.int32()).buffers()[1]
+    return pa.Array.from_buffers(pa.string(), 1, [None, offsets, pa.py_buffer(b"\xff\xfe")])
+
+def test_import_validation_full():
+    with pytest.raises(pa.ArrowException, match="Invalid UTF8 sequence"):
+        rust.round_trip_array_with_validation(_invalid_utf8_array(), "full")
+
+@pytest.mark.parametrize("validation", ["structural", "none"])
+def test_import_validation_skips_values(validation):
+    a = _invalid_utf8_array()
+    b = rust.round_trip_array_with_validation(a, validation)
+    assert b.type == pa.string()
+    assert len(b) == 1
+
+def test_import_validation_default_is_full():
+    with pytest.raises(pa.ArrowException, match="Invalid UTF8 sequence"):
+        rust.round_trip_array(_invalid_utf8_array())
+
+@_requires_pycapsule
+def test_import_validation_record_batch_pycapsule():
+    batch = pa.RecordBatch.from_arrays([_invalid_utf8_array()], names=["a"])
+    with pytest.raises(pa.ArrowException, match="Invalid UTF8 sequence"):
+        rust.round_trip_record_batch(batch)
+
+def test_import_validation_none_rejects_null_buffers():
+    a = pa.Array.from_buffers(pa.string(), 3, [None, None, None])
+    with pytest.raises(pa.ArrowException):
+        rust.round_trip_array_with_validation(a, "none")
@@ -82,6 +82,13 @@ pub struct FFI_ArrowSchema {
     private_data: *mut c_void,
 }
 
+// SAFETY: the C Data Interface does not tie a schema to the thread that
+// created it, and its release callback may be called from any thread, as
+// happens when a PyCapsule holding it is garbage collected. The struct owns
+// its format, name, metadata and children, which only the release callback
+// frees, so moving it to another thread does not leave shared state behind.
+unsafe impl Send for FFI_ArrowSchema {}
+
 struct SchemaPrivateData {
     children: Box<[*mut FFI_ArrowSchema]>,
     dictionary: *mut FFI_ArrowSchema,
@@ -408,6 +415,11 @@ impl TryFrom<&FFI_ArrowSchema> for DataType {
                 let map_keys_sorted = c_schema.map_keys_sorted();
                 DataType::Map(Arc::new(Field::try_from(c_child)?), map_keys_sorted)
             }
+            "+r" => {
+                let run_ends = Field::try_from(c_schema.child(0))?;
+                let values = Field::try_from(c_schema.child(1))?;
+                DataType::RunEndEncoded(Arc::new(run_ends), Arc::new(values))
+            }
             // Parametrized types, requiring string parse
             other => {
                 match other.splitn(2, ':').collect::<Vec<&str>>().as_slice() {
@@ -556,7 +568,16 @@ impl TryFrom<&FFI_ArrowSchema> for Field {
 
     fn try_from(c_schema: &FFI_ArrowSchema) -> Result<Self, ArrowError> {
         let dtype = DataType::try_from(c_schema)?;
-        let mut field = Field::new(c_schema.name(), dtype, c_schema.nullable());
+        let mut field = match dtype {
+            DataType::Dictionary(_, _) => Field::new_dict(
+                c_schema.name(),
+                dtype,
+                c_schema.nullable(),
+                0,
+                c_schema.dictionary_ordered(),
+            ),
+            _ => Field::new(c_schema.name(), dtype, c_schema.nullable()),
+        };
         field.set_metadata(c_schema.metadata()?);
         Ok(field)
     }
@@ -600,6 +621,10 @@ impl TryFrom<&DataType> for FFI_ArrowSchema {
                 .iter()
                 .map(FFI_ArrowSchema::try_from)
                 .collect::<Result<Vec<_>, ArrowError>>()?,
+            DataType::RunEndEncoded(run_ends, values) => vec![
+                FFI_ArrowSchema::try_from(run_ends.as_ref())?,
+                FFI_ArrowSchema::try_from(values.as_ref())?,
+            ],
             _ => vec![],
         };
         let dictionary = if let DataType::Dictionary(_, value_data_type) = dtype {
@@ -664,6 +689,7 @@ fn get_format_string(dtype: &DataType) -> Result<String, ArrowError> {
         DataType::LargeList(_) => Ok("+L".to_string()),
         DataType::Struct(_) => Ok("+s".to_string()),
         DataType::Map(_, _) => Ok("+m".to_string()),
+        DataType::RunEndEncoded(_, _) => Ok("+r".to_string()),
         DataType::Dictionary(key_data_type, _) => get_format_string(key_data_type),
         DataType::Union(fields, mode) => {
             let formats = fields
@@ -791,6 +817,10 @@ mod tests {
             DataType::Utf8,
             true,
         )])));
+        round_trip_type(DataType::RunEndEncoded(
+            Arc::new(Field::new("run_ends", DataType::Int32, false)),
+            Arc::new(Field::new("values", DataType::Utf8, true)),
+        ));
     }
 
     #[test]
@@ -853,6 +883,38 @@ mod tests {
         assert!(arrow_schema.child(0).dictionary_ordered());
     }
 
+    #[test]
+    fn test_dictionary_ordered_round_trip() {
+        let dict =
+            |key: DataType| DataType::Dictionary(Box::new(key), Box::new(DataType::Utf8));
+        let ordered = Field::new_dict("ordered", dict(DataType::Int8), true, 0, true);
+        let unordered =
+            Field::new_dict("unordered", dict(DataType::Int16), true, 0, false);
+        let list = Field::new_list("list", ordered.clone(), true);
+        let strct = Field::new_struct("struct", vec![ordered.clone()], true);
+        let schema = Schema::new(vec![ordered, unordered, list, strct]);
+
+        let c_schema = FFI_ArrowSchema::try_from(&schema).unwrap();
+        let restored = Schema::try_from(&c_schema).unwrap();
+        assert_eq!(restored, schema);
+
+        assert_eq!(restored.field(0).dict_is_ordered(), Some(true));
+        assert_eq!(restored.field(1).dict_is_ordered(), Some(false));
+        match restored.field(2).data_type() {
+            DataType::List(item) => {
+                assert_eq!(item.data_type(), &dict(DataType::Int8));
+                assert_eq!(item.dict_is_ordered(), Some(true));
+            }
+            other => panic!("expected list, got {other}"),
+        }
+        match restored.field(3).data_type() {
+            DataType::Struct(children) => {
+                assert_eq!(children[0].dict_is_ordered(), Some(true));
+            }
+            other => panic!("expected struct, got {other}"),
+        }
+    }
+
     #[test]
     fn test_set_field_metadata() {
         let metadata_cases: Vec<HashMap<String, String>> = vec![
@@ -877,4 +939,19 @@ mod tests {
             assert_eq!(field.metadata(), &metadata);
         }
     }
+
+    #[test]
+    fn test_nested_field_metadata() {
+        let metadata: HashMap<String, String> =
+            [("ARROW:extension:name".to_string(), "uuid".to_string())].into();
+        let child = Field::new("a", DataType::FixedSizeBinary(16), true)
+            .with_metadata(metadata.clone());
+        let list =
+            Field::new_list("b", child.clone(), true).with_metadata(metadata.clone());
+        let dtype = DataType::Struct(vec![child, list].into());
+        let schema = Schema::new(vec![Field::new("c", dtype, true)])
+            .with_metadata([("hello".to_string(), "world".to_string())].into());
+
+        round_trip_schema(schema);
+    }
 }
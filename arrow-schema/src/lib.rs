@@ -31,6 +31,8 @@ use std::ops;
 
 #[cfg(feature = "ffi")]
 pub mod ffi;
+#[cfg(feature = "pyarrow")]
+pub mod pyarrow;
 
 /// Options that define the sort order of a given column
 #[derive(Clone, Hash, Copy, Debug, Eq, PartialEq, Ord, PartialOrd)]
@@ -0,0 +1,211 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Pass [DataType], [Field] and [Schema] from and to PyArrow, using Arrow's
+//! [C Data Interface](https://arrow.apache.org/docs/format/CDataInterface.html)
+//! and [pyo3](https://docs.rs/pyo3/latest/pyo3/).
+//!
+//! This is for crates that only exchange types with Python, such as reading a
+//! schema without reading any data, and so do not need the `arrow` crate. The
+//! `FromPyArrow` and `ToPyArrow` traits of `arrow::pyarrow` are implemented for
+//! these types with the functions of this module.
+//!
+//! Objects from other libraries are accepted too, if they implement
+//! `__arrow_c_schema__` from the
+//! [Arrow PyCapsule Interface](https://arrow.apache.org/docs/format/CDataInterface/PyCapsuleInterface.html).
+//!
+//! ```ignore
+//! #[pyfunction]
+//! fn field_names(schema: &PyAny) -> PyResult<Vec<String>> {
+//!     let schema = schema_from_pyarrow(schema)?;
+//!     Ok(schema.fields().iter().map(|f| f.name().clone()).collect())
+//! }
+//! ```
+
+use std::ffi::CString;
+
+use pyo3::exceptions::{PyOSError, PyTypeError, PyValueError};
+use pyo3::ffi::Py_uintptr_t;
+use pyo3::import_exception;
+use pyo3::prelude::*;
+use pyo3::types::PyCapsule;
+
+use crate::ffi::FFI_ArrowSchema;
+use crate::{ArrowError, DataType, Field, Schema};
+
+import_exception!(pyarrow, ArrowException);
+pub type PyArrowException = ArrowException;
+
+import_exception!(pyarrow, ArrowInvalid);
+import_exception!(pyarrow, ArrowNotImplementedError);
+import_exception!(pyarrow, ArrowTypeError);
+
+/// Converts an [ArrowError] into the Python exception pyarrow would raise for it,
+/// falling back to [PyArrowException].
+pub fn to_py_err(err: ArrowError) -> PyErr {
+    match err {
+        ArrowError::InvalidArgumentError(_) | ArrowError::ComputeError(_) => {
+            ArrowInvalid::new_err(err.to_string())
+        }
+        ArrowError::IoError(_) => PyOSError::new_err(err.to_string()),
+        ArrowError::NotYetImplemented(_) => {
+            ArrowNotImplementedError::new_err(err.to_string())
+        }
+        ArrowError::SchemaError(_) => ArrowTypeError::new_err(err.to_string()),
+        _ => PyArrowException::new_err(err.to_string()),
+    }
+}
+
+/// Checks that `value` is an instance of `pyarrow.{expected}`, including
+/// subclasses such as extension types, raising a `TypeError` otherwise.
+pub fn validate_class(expected: &str, value: &PyAny) -> PyResult<()> {
+    let pyarrow = PyModule::import(value.py(), "pyarrow")?;
+    let class = pyarrow.getattr(expected)?;
+    if !value.is_instance(class)? {
+        let found = value.get_type();
+        return Err(PyTypeError::new_err(format!(
+            "Expected instance of pyarrow.{}, got {}.{}",
+            expected,
+            found.getattr("__module__")?.extract::<&str>()?,
+            found.name()?,
+        )));
+    }
+    Ok(())
+}
+
+/// Checks that `value`, if it is an instance of one of the pyarrow schema
+/// classes, is an instance of `pyarrow.{expected}`.
+///
+/// The PyCapsule Interface alone cannot tell these classes apart: a
+/// `pyarrow.Field` exports the same capsule as a `pyarrow.DataType` would, only
+/// with a name.
+fn validate_schema_class(expected: &str, value: &PyAny) -> PyResult<()> {
+    // objects from other libraries can be imported without pyarrow installed
+    let pyarrow = match PyModule::import(value.py(), "pyarrow") {
+        Ok(pyarrow) => pyarrow,
+        Err(_) => return Ok(()),
+    };
+    for class in ["DataType", "Field", "Schema"] {
+        if value.is_instance(pyarrow.getattr(class)?)? {
+            return validate_class(expected, value);
+        }
+    }
+    Ok(())
+}
+
+/// Checks that `capsule` has the name given to it by the
+/// [Arrow PyCapsule Interface](https://arrow.apache.org/docs/format/CDataInterface/PyCapsuleInterface.html).
+fn validate_pycapsule(capsule: &PyCapsule, name: &str) -> PyResult<()> {
+    let capsule_name = match capsule.name()? {
+        Some(capsule_name) => capsule_name.to_str()?,
+        None => return Err(PyValueError::new_err("Expected PyCapsule to have a name")),
+    };
+    if capsule_name != name {
+        return Err(PyValueError::new_err(format!(
+            "Expected name '{name}' in PyCapsule, instead got '{capsule_name}'"
+        )));
+    }
+    Ok(())
+}
+
+/// Moves the C struct out of a PyCapsule named `name`, leaving `empty` in its
+/// place so that the capsule's destructor does not release it a second time.
+///
+/// # Safety
+/// A capsule named `name` must point to a valid `T`.
+pub unsafe fn take_from_capsule<T>(capsule: &PyAny, name: &str, empty: T) -> PyResult<T> {
+    let capsule = capsule.downcast::<PyCapsule>()?;
+    validate_pycapsule(capsule, name)?;
+    Ok(std::ptr::replace(capsule.pointer() as *mut T, empty))
+}
+
+/// Exports `value`, which must be a `pyarrow.{expected}` or implement
+/// `__arrow_c_schema__`, to a [FFI_ArrowSchema].
+fn c_schema_from_pyarrow(expected: &str, value: &PyAny) -> PyResult<FFI_ArrowSchema> {
+    if value.hasattr("__arrow_c_schema__")? {
+        validate_schema_class(expected, value)?;
+        let capsule = value.call_method0("__arrow_c_schema__")?;
+        return unsafe {
+            take_from_capsule(capsule, "arrow_schema", FFI_ArrowSchema::empty())
+        };
+    }
+    validate_class(expected, value)?;
+
+    let c_schema = FFI_ArrowSchema::empty();
+    let c_schema_ptr = &c_schema as *const FFI_ArrowSchema;
+    value.call_method1("_export_to_c", (c_schema_ptr as Py_uintptr_t,))?;
+    Ok(c_schema)
+}
+
+/// Creates a `pyarrow.{class}` from `c_schema`, using the PyCapsule Interface if
+/// the installed pyarrow supports it.
+fn c_schema_to_pyarrow(
+    py: Python,
+    class: &str,
+    c_schema: FFI_ArrowSchema,
+) -> PyResult<PyObject> {
+    let module = py.import("pyarrow")?;
+    let class = module.getattr(class)?;
+    if class.hasattr("_import_from_c_capsule")? {
+        let capsule = PyCapsule::new(py, c_schema, Some(CString::new("arrow_schema")?))?;
+        let object = class.call_method1("_import_from_c_capsule", (capsule,))?;
+        return Ok(object.into());
+    }
+
+    let c_schema_ptr = &c_schema as *const FFI_ArrowSchema;
+    let object = class.call_method1("_import_from_c", (c_schema_ptr as Py_uintptr_t,))?;
+    Ok(object.into())
+}
+
+/// Converts a `pyarrow.DataType`, or an object implementing `__arrow_c_schema__`,
+/// into a [DataType].
+pub fn data_type_from_pyarrow(value: &PyAny) -> PyResult<DataType> {
+    let c_schema = c_schema_from_pyarrow("DataType", value)?;
+    DataType::try_from(&c_schema).map_err(to_py_err)
+}
+
+/// Creates a `pyarrow.DataType` from a [DataType].
+pub fn data_type_to_pyarrow(py: Python, data_type: &DataType) -> PyResult<PyObject> {
+    let c_schema = FFI_ArrowSchema::try_from(data_type).map_err(to_py_err)?;
+    c_schema_to_pyarrow(py, "DataType", c_schema)
+}
+
+/// Converts a `pyarrow.Field`, or an object implementing `__arrow_c_schema__`,
+/// into a [Field].
+pub fn field_from_pyarrow(value: &PyAny) -> PyResult<Field> {
+    let c_schema = c_schema_from_pyarrow("Field", value)?;
+    Field::try_from(&c_schema).map_err(to_py_err)
+}
+
+/// Creates a `pyarrow.Field` from a [Field].
+pub fn field_to_pyarrow(py: Python, field: &Field) -> PyResult<PyObject> {
+    let c_schema = FFI_ArrowSchema::try_from(field).map_err(to_py_err)?;
+    c_schema_to_pyarrow(py, "Field", c_schema)
+}
+
+/// Converts a `pyarrow.Schema`, or an object implementing `__arrow_c_schema__`,
+/// into a [Schema].
+pub fn schema_from_pyarrow(value: &PyAny) -> PyResult<Schema> {
+    let c_schema = c_schema_from_pyarrow("Schema", value)?;
+    Schema::try_from(&c_schema).map_err(to_py_err)
+}
+
+/// Creates a `pyarrow.Schema` from a [Schema].
+pub fn schema_to_pyarrow(py: Python, schema: &Schema) -> PyResult<PyObject> {
+    let c_schema = FFI_ArrowSchema::try_from(schema).map_err(to_py_err)?;
+    c_schema_to_pyarrow(py, "Schema", c_schema)
+}
@@ -0,0 +1,58 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+#[macro_use]
+extern crate criterion;
+use criterion::Criterion;
+
+extern crate arrow;
+
+use arrow::array::*;
+use arrow::pyarrow::{FromPyArrowWithOptions, ImportOptions, ToPyArrow, ValidationLevel};
+use pyo3::Python;
+
+fn import_benchmark(c: &mut Criterion) {
+    pyo3::prepare_freethreaded_python();
+
+    let array = StringArray::from_iter_values(
+        (0..10_000_000).map(|i| format!("value-{}", i % 1000)),
+    );
+
+    Python::with_gil(|py| {
+        let value = array.to_data().to_pyarrow(py).unwrap();
+        let value = value.as_ref(py);
+
+        for (name, validation) in [
+            ("full", ValidationLevel::Full),
+            ("structural", ValidationLevel::Structural),
+            ("none", ValidationLevel::None),
+        ] {
+            // The array is built above, so it is known to be valid
+            let options = unsafe { ImportOptions::default().with_validation(validation) };
+            c.bench_function(
+                &format!("import_utf8_array 10000000 validation={name}"),
+                |b| {
+                    b.iter(|| {
+                        ArrayData::from_pyarrow_with_options(value, options).unwrap()
+                    })
+                },
+            );
+        }
+    });
+}
+
+criterion_group!(benches, import_benchmark);
+criterion_main!(benches);
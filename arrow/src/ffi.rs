@@ -253,6 +253,23 @@ pub trait ArrowArrayRef {
             child_data.push(d.to_data()?);
         }
 
+        // The C data interface applies the offset of a struct to its children,
+        // whereas the children of a struct `ArrayData` are already sliced
+        if matches!(data_type, DataType::Struct(_)) && offset != 0 {
+            child_data = child_data
+                .iter()
+                .map(|child| {
+                    if child.len() < offset + len {
+                        return Err(ArrowError::CDataInterface(format!(
+                            "Struct child of length {} is shorter than the parent offset {offset} plus length {len}",
+                            child.len()
+                        )));
+                    }
+                    Ok(child.slice(offset, len))
+                })
+                .collect::<Result<_>>()?;
+        }
+
         // Should FFI be checking validity?
         Ok(unsafe {
             ArrayData::new_unchecked(
@@ -488,11 +505,13 @@ impl ArrowArray {
 mod tests {
     use super::*;
     use crate::array::{
-        make_array, Array, ArrayData, BooleanArray, Decimal128Array, DictionaryArray,
-        DurationSecondArray, FixedSizeBinaryArray, FixedSizeListArray,
-        GenericBinaryArray, GenericListArray, GenericStringArray, Int32Array, MapArray,
-        OffsetSizeTrait, Time32MillisecondArray, TimestampMillisecondArray, UInt32Array,
+        make_array, Array, ArrayData, ArrayRef, BooleanArray, Decimal128Array,
+        DictionaryArray, DurationSecondArray, FixedSizeBinaryArray, FixedSizeListArray,
+        GenericBinaryArray, GenericListArray, GenericStringArray, Int32Array, ListArray,
+        MapArray, OffsetSizeTrait, RunArray, Time32MillisecondArray,
+        TimestampMillisecondArray, UInt32Array,
     };
+    use crate::buffer::OffsetBuffer;
     use crate::compute::kernels;
     use crate::datatypes::{Field, Int8Type};
     use arrow_array::builder::UnionBuilder;
@@ -996,6 +1015,63 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_run_end_encoded() -> Result<()> {
+        let run_ends = Int32Array::from(vec![2, 3, 6]);
+        let values = GenericStringArray::<i32>::from(vec![Some("a"), None, Some("b")]);
+        let array = RunArray::try_new(&run_ends, &values)?;
+
+        for data in [array.to_data(), array.to_data().slice(1, 4)] {
+            // export it
+            let exported = ArrowArray::try_from(data.clone())?;
+            assert_eq!(exported.array.num_buffers(), 0);
+
+            // (simulate consumer) import it
+            let imported = ArrayData::try_from(exported)?;
+            imported.validate_full()?;
+            // run arrays don't support logical comparison, so compare the parts
+            assert_eq!(imported.data_type(), data.data_type());
+            assert_eq!(imported.offset(), data.offset());
+            assert_eq!(imported.len(), data.len());
+            assert_eq!(imported.child_data(), data.child_data());
+        }
+
+        // (drop/release)
+        Ok(())
+    }
+
+    #[test]
+    fn test_nested_dictionary() -> Result<()> {
+        let values = vec!["a", "aaa", "aaa", "b"];
+        let dict_array: ArrayRef =
+            Arc::new(values.into_iter().collect::<DictionaryArray<Int8Type>>());
+        let list_array = ListArray::new(
+            Arc::new(Field::new("item", dict_array.data_type().clone(), true)),
+            OffsetBuffer::new(vec![0, 1, 1, 4].into()),
+            dict_array.clone(),
+            None,
+        );
+        let struct_array = StructArray::from(vec![(
+            Arc::new(Field::new("dict", dict_array.data_type().clone(), true)),
+            dict_array,
+        )]);
+
+        for array in [&list_array as &dyn Array, &struct_array] {
+            // export it
+            let exported = ArrowArray::try_from(array.to_data())?;
+
+            // (simulate consumer) import it
+            let data = ArrayData::try_from(exported)?;
+
+            // verify the key type is preserved
+            assert_eq!(data.data_type(), array.data_type());
+            assert_eq!(make_array(data).as_ref(), array);
+        }
+
+        // (drop/release)
+        Ok(())
+    }
+
     #[test]
     #[allow(deprecated)]
     fn test_export_array_into_raw() -> Result<()> {
@@ -1118,6 +1194,32 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_sliced_struct_array() -> Result<()> {
+        let struct_array = StructArray::from(vec![(
+            Arc::new(Field::new("a", DataType::Int32, true)),
+            Arc::new(Int32Array::from(vec![Some(2), None, Some(6), Some(8)]))
+                as Arc<dyn Array>,
+        )]);
+        let sliced = struct_array.to_data().slice(1, 3);
+
+        // export it
+        let array = ArrowArray::try_from(sliced.clone())?;
+
+        // the children are already sliced, so the parent must not carry an offset
+        assert_eq!(array.array.offset(), 0);
+        assert_eq!(array.array.child(0).offset(), 1);
+
+        // (simulate consumer) import it
+        let data = ArrayData::try_from(array)?;
+        let array = make_array(data);
+
+        let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+        assert_eq!(array, &StructArray::from(sliced));
+
+        Ok(())
+    }
+
     #[test]
     fn test_union_sparse_array() -> Result<()> {
         let mut builder = UnionBuilder::new_sparse();
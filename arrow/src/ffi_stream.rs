@@ -63,7 +63,7 @@
 use std::ptr::addr_of;
 use std::{
     convert::TryFrom,
-    ffi::CString,
+    ffi::{CStr, CString},
     os::raw::{c_char, c_int, c_void},
     sync::Arc,
 };
@@ -74,11 +74,16 @@ use crate::datatypes::{Schema, SchemaRef};
 use crate::error::ArrowError;
 use crate::error::Result;
 use crate::ffi::*;
-use crate::record_batch::{RecordBatch, RecordBatchReader};
+use crate::record_batch::{RecordBatch, RecordBatchOptions, RecordBatchReader};
 
 const ENOMEM: i32 = 12;
 const EIO: i32 = 5;
 const EINVAL: i32 = 22;
+#[cfg(target_os = "linux")]
+const ENOSYS: i32 = 38;
+#[cfg(target_os = "windows")]
+const ENOSYS: i32 = 40;
+#[cfg(not(any(target_os = "linux", target_os = "windows")))]
 const ENOSYS: i32 = 78;
 
 /// ABI-compatible struct for `ArrayStream` from C Stream Interface
@@ -105,6 +110,13 @@ pub struct FFI_ArrowArrayStream {
     pub private_data: *mut c_void,
 }
 
+// SAFETY: the C Stream Interface does not tie a stream to the thread that
+// created it. Its callbacks, including `release`, may be invoked from any
+// thread as long as they are not invoked concurrently, which holds as moving
+// the struct gives the receiving thread exclusive access to it. Streams
+// exported by this crate require their reader to be `Send` for the same reason.
+unsafe impl Send for FFI_ArrowArrayStream {}
+
 // callback used to drop [FFI_ArrowArrayStream] when it is exported.
 unsafe extern "C" fn release_stream(stream: *mut FFI_ArrowArrayStream) {
     if stream.is_null() {
@@ -123,8 +135,8 @@ unsafe extern "C" fn release_stream(stream: *mut FFI_ArrowArrayStream) {
 }
 
 struct StreamPrivateData {
-    batch_reader: Box<dyn RecordBatchReader>,
-    last_error: String,
+    batch_reader: Box<dyn RecordBatchReader + Send>,
+    last_error: Option<CString>,
 }
 
 // The callback used to get array schema
@@ -146,8 +158,12 @@ unsafe extern "C" fn get_next(
 // The callback used to get the error from last operation on the `FFI_ArrowArrayStream`
 unsafe extern "C" fn get_last_error(stream: *mut FFI_ArrowArrayStream) -> *const c_char {
     let mut ffi_stream = ExportedArrayStream { stream };
-    let last_error = ffi_stream.get_last_error();
-    CString::new(last_error.as_str()).unwrap().into_raw()
+    // The returned string is owned by the stream and remains valid until the
+    // next call to one of its callbacks
+    match ffi_stream.get_last_error() {
+        Some(err) => err.as_ptr(),
+        None => std::ptr::null(),
+    }
 }
 
 impl Drop for FFI_ArrowArrayStream {
@@ -161,10 +177,10 @@ impl Drop for FFI_ArrowArrayStream {
 
 impl FFI_ArrowArrayStream {
     /// Creates a new [`FFI_ArrowArrayStream`].
-    pub fn new(batch_reader: Box<dyn RecordBatchReader>) -> Self {
+    pub fn new(batch_reader: Box<dyn RecordBatchReader + Send>) -> Self {
         let private_data = Box::new(StreamPrivateData {
             batch_reader,
-            last_error: String::new(),
+            last_error: None,
         });
 
         Self {
@@ -210,7 +226,8 @@ impl ExportedArrayStream {
                 0
             }
             Err(ref err) => {
-                private_data.last_error = err.to_string();
+                private_data.last_error =
+                    Some(CString::new(err.to_string()).unwrap_or_default());
                 get_error_code(err)
             }
         }
@@ -236,15 +253,16 @@ impl ExportedArrayStream {
                     0
                 } else {
                     let err = &next_batch.unwrap_err();
-                    private_data.last_error = err.to_string();
+                    private_data.last_error =
+                        Some(CString::new(err.to_string()).unwrap_or_default());
                     get_error_code(err)
                 }
             }
         }
     }
 
-    pub fn get_last_error(&mut self) -> &String {
-        &self.get_private_data().last_error
+    pub fn get_last_error(&mut self) -> Option<&CString> {
+        self.get_private_data().last_error.as_ref()
     }
 }
 
@@ -257,13 +275,25 @@ fn get_error_code(err: &ArrowError) -> i32 {
     }
 }
 
+/// Converts an error code returned by an imported stream back into an [ArrowError],
+/// the inverse of [get_error_code].
+fn get_error_from_code(code: i32, message: String) -> ArrowError {
+    match code {
+        ENOSYS => ArrowError::NotYetImplemented(message),
+        ENOMEM => ArrowError::MemoryError(message),
+        EIO => ArrowError::IoError(message),
+        EINVAL => ArrowError::InvalidArgumentError(message),
+        _ => ArrowError::CDataInterface(message),
+    }
+}
+
 /// A `RecordBatchReader` which imports Arrays from `FFI_ArrowArrayStream`.
 /// Struct used to fetch `RecordBatch` from the C Stream Interface.
 /// Its main responsibility is to expose `RecordBatchReader` functionality
 /// that requires [FFI_ArrowArrayStream].
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 pub struct ArrowArrayStreamReader {
-    stream: Arc<FFI_ArrowArrayStream>,
+    stream: FFI_ArrowArrayStream,
     schema: SchemaRef,
 }
 
@@ -291,21 +321,16 @@ impl ArrowArrayStreamReader {
     /// Creates a new `ArrowArrayStreamReader` from a `FFI_ArrowArrayStream`.
     /// This is used to import from the C Stream Interface.
     #[allow(dead_code)]
-    pub fn try_new(stream: FFI_ArrowArrayStream) -> Result<Self> {
+    pub fn try_new(mut stream: FFI_ArrowArrayStream) -> Result<Self> {
         if stream.release.is_none() {
             return Err(ArrowError::CDataInterface(
                 "input stream is already released".to_string(),
             ));
         }
 
-        let stream_ptr = Arc::into_raw(Arc::new(stream)) as *mut FFI_ArrowArrayStream;
+        let schema = get_stream_schema(&mut stream)?;
 
-        let schema = get_stream_schema(stream_ptr)?;
-
-        Ok(Self {
-            stream: unsafe { Arc::from_raw(stream_ptr) },
-            schema,
-        })
+        Ok(Self { stream, schema })
     }
 
     /// Creates a new `ArrowArrayStreamReader` from a raw pointer of `FFI_ArrowArrayStream`.
@@ -324,21 +349,17 @@ impl ArrowArrayStreamReader {
     }
 
     /// Get the last error from `ArrowArrayStreamReader`
-    fn get_stream_last_error(&self) -> Option<String> {
-        self.stream.get_last_error?;
-
-        let stream_ptr = Arc::as_ptr(&self.stream) as *mut FFI_ArrowArrayStream;
+    fn get_stream_last_error(&mut self) -> Option<String> {
+        let get_last_error = self.stream.get_last_error?;
 
-        let error_str = unsafe {
-            let c_str = self.stream.get_last_error.unwrap()(stream_ptr) as *mut c_char;
-            CString::from_raw(c_str).into_string()
-        };
-
-        if let Err(err) = error_str {
-            Some(err.to_string())
-        } else {
-            Some(error_str.unwrap())
+        let c_str = unsafe { get_last_error(&mut self.stream) };
+        if c_str.is_null() {
+            return None;
         }
+
+        // The string is owned by the stream, so copy it out
+        let c_str = unsafe { CStr::from_ptr(c_str) };
+        Some(c_str.to_string_lossy().into_owned())
     }
 }
 
@@ -346,7 +367,7 @@ impl Iterator for ArrowArrayStreamReader {
     type Item = Result<RecordBatch>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let stream_ptr = Arc::as_ptr(&self.stream) as *mut FFI_ArrowArrayStream;
+        let stream_ptr = &mut self.stream as *mut FFI_ArrowArrayStream;
 
         let empty_array = Arc::new(FFI_ArrowArray::empty());
         let array_ptr = Arc::into_raw(empty_array) as *mut FFI_ArrowArray;
@@ -370,16 +391,28 @@ impl Iterator for ArrowArrayStreamReader {
             }
             .to_data()
             .ok()?;
-
-            let record_batch = RecordBatch::from(StructArray::from(data));
-
-            Some(Ok(record_batch))
+            let len = data.len();
+
+            // Build the batch with the stream's schema, as the struct array
+            // does not carry the schema level metadata
+            let (_, columns, nulls) = StructArray::from(data).into_parts();
+            if nulls.map(|n| n.null_count()).unwrap_or_default() != 0 {
+                return Some(Err(ArrowError::CDataInterface(
+                    "Cannot import a RecordBatch from a StructArray with top-level nulls"
+                        .to_string(),
+                )));
+            }
+            let options = RecordBatchOptions::new().with_row_count(Some(len));
+            Some(RecordBatch::try_new_with_options(
+                schema_ref, columns, &options,
+            ))
         } else {
             unsafe { Arc::from_raw(array_ptr) };
 
-            let last_error = self.get_stream_last_error();
-            let err = ArrowError::CDataInterface(last_error.unwrap());
-            Some(Err(err))
+            let last_error = self
+                .get_stream_last_error()
+                .unwrap_or_else(|| format!("Error code: {ret_code}"));
+            Some(Err(get_error_from_code(ret_code, last_error)))
         }
     }
 }
@@ -396,7 +429,7 @@ impl RecordBatchReader for ArrowArrayStreamReader {
 /// Assumes that the pointer represents valid C Stream Interfaces, both in memory
 /// representation and lifetime via the `release` mechanism.
 pub unsafe fn export_reader_into_raw(
-    reader: Box<dyn RecordBatchReader>,
+    reader: Box<dyn RecordBatchReader + Send>,
     out_stream: *mut FFI_ArrowArrayStream,
 ) {
     let stream = FFI_ArrowArrayStream::new(reader);
@@ -408,18 +441,20 @@ pub unsafe fn export_reader_into_raw(
 mod tests {
     use super::*;
 
+    use std::collections::HashMap;
+
     use crate::array::Int32Array;
-    use crate::datatypes::{Field, Schema};
+    use crate::datatypes::{DataType, Field, Schema};
 
     struct TestRecordBatchReader {
         schema: SchemaRef,
-        iter: Box<dyn Iterator<Item = Result<RecordBatch>>>,
+        iter: Box<dyn Iterator<Item = Result<RecordBatch>> + Send>,
     }
 
     impl TestRecordBatchReader {
         pub fn new(
             schema: SchemaRef,
-            iter: Box<dyn Iterator<Item = Result<RecordBatch>>>,
+            iter: Box<dyn Iterator<Item = Result<RecordBatch>> + Send>,
         ) -> Box<TestRecordBatchReader> {
             Box::new(TestRecordBatchReader { schema, iter })
         }
@@ -501,11 +536,16 @@ mod tests {
     }
 
     fn _test_round_trip_import(arrays: Vec<Arc<dyn Array>>) -> Result<()> {
-        let schema = Arc::new(Schema::new(vec![
-            Field::new("a", arrays[0].data_type().clone(), true),
-            Field::new("b", arrays[1].data_type().clone(), true),
-            Field::new("c", arrays[2].data_type().clone(), true),
-        ]));
+        let metadata = HashMap::from([("foo".to_owned(), "bar".to_owned())]);
+        let schema = Arc::new(
+            Schema::new(vec![
+                Field::new("a", arrays[0].data_type().clone(), true)
+                    .with_metadata(metadata.clone()),
+                Field::new("b", arrays[1].data_type().clone(), true),
+                Field::new("c", arrays[2].data_type().clone(), true),
+            ])
+            .with_metadata(metadata),
+        );
         let batch = RecordBatch::try_new(schema.clone(), arrays).unwrap();
         let iter = Box::new(vec![batch.clone(), batch.clone()].into_iter().map(Ok)) as _;
 
@@ -546,4 +586,44 @@ mod tests {
 
         _test_round_trip_import(vec![array.clone(), array.clone(), array])
     }
+
+    #[test]
+    fn test_stream_drop_releases_reader() {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, true)]));
+        let iter = Box::new(std::iter::empty()) as _;
+        let reader = TestRecordBatchReader::new(schema.clone(), iter);
+        assert_eq!(Arc::strong_count(&schema), 2);
+
+        // A stream that is never consumed releases its reader when dropped
+        let stream = FFI_ArrowArrayStream::new(reader);
+        drop(stream);
+        assert_eq!(Arc::strong_count(&schema), 1);
+
+        // An empty stream has nothing to release
+        drop(FFI_ArrowArrayStream::empty());
+    }
+
+    #[test]
+    fn test_stream_error_round_trip() {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, true)]));
+        let errors = vec![
+            ArrowError::IoError("io".to_string()),
+            ArrowError::NotYetImplemented("nyi".to_string()),
+            ArrowError::InvalidArgumentError("invalid".to_string()),
+        ];
+        let iter = Box::new(errors.into_iter().map(Err)) as _;
+        let reader = TestRecordBatchReader::new(schema, iter);
+
+        let stream = FFI_ArrowArrayStream::new(reader);
+        let mut stream_reader = ArrowArrayStreamReader::try_new(stream).unwrap();
+
+        let err = stream_reader.next().unwrap().unwrap_err();
+        assert!(matches!(err, ArrowError::IoError(msg) if msg.contains("io")));
+        let err = stream_reader.next().unwrap().unwrap_err();
+        assert!(matches!(err, ArrowError::NotYetImplemented(msg) if msg.contains("nyi")));
+        let err = stream_reader.next().unwrap().unwrap_err();
+        assert!(
+            matches!(err, ArrowError::InvalidArgumentError(msg) if msg.contains("invalid"))
+        );
+    }
 }
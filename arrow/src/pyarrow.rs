@@ -15,129 +15,343 @@
 // specific language governing permissions and limitations
 // under the License.
 
-//! This module demonstrates a minimal usage of Rust's C data interface to pass
-//! arrays from and to Python.
+//! Pass Arrow objects from and to PyArrow, using Arrow's
+//! [C Data Interface](https://arrow.apache.org/docs/format/CDataInterface.html)
+//! and [pyo3](https://docs.rs/pyo3/latest/pyo3/).
+//! For underlying implementation, see the [ffi] module.
+//!
+//! One can use these to write Python functions that take and return PyArrow
+//! objects, with automatic conversion to corresponding arrow-rs types.
+//!
+//! ```ignore
+//! #[pyfunction]
+//! fn double_array(array: PyArrowType<Int32Array>) -> PyResult<PyArrowType<Int32Array>> {
+//!     let array = array.0; // Extract from PyArrowType wrapper
+//!     let array: Int32Array = array.iter().map(|x| x.map(|x| x * 2)).collect();
+//!     Ok(PyArrowType(array))
+//! }
+//! ```
+//!
+//! | pyarrow type                | arrow-rs type                                           |
+//! |-----------------------------|---------------------------------------------------------|
+//! | `pyarrow.DataType`          | [DataType]                                              |
+//! | `pyarrow.Field`             | [Field]                                                 |
+//! | `pyarrow.Schema`            | [Schema]                                                |
+//! | `pyarrow.Array`             | [ArrayData]                                             |
+//! | `pyarrow.Array`             | [ArrayRef]                                              |
+//! | `pyarrow.Array`             | [PrimitiveArray], [GenericByteArray], [BooleanArray]    |
+//! | `pyarrow.ChunkedArray`      | [PyArrowChunkedArray]                                   |
+//! | `pyarrow.Buffer`            | [Buffer]                                                |
+//! | `pyarrow.RecordBatch`       | [RecordBatch]                                           |
+//! | `pyarrow.RecordBatchReader` | [ArrowArrayStreamReader]                                |
+//! | `pyarrow.RecordBatchReader` | `Box<dyn RecordBatchReader + Send>`                     |
+//! | `pyarrow.RecordBatchReader` | [RecordBatchIterator] (export only)                     |
+//! | `pyarrow.Table`             | [PyArrowTable]                                          |
+//! | `pyarrow.Scalar`            | `bool`, `i32`, `i64`, `f32`, `f64`, `String`, `Vec<u8>` |
+//! | `pyarrow.Scalar`            | [PyArrowScalar]                                         |
+//!
+//! Conversion from Python is provided by [`FromPyArrow`], conversion to Python
+//! by [`ToPyArrow`] for types that can be exported by reference, and by
+//! [`IntoPyArrow`] for types, such as readers, that are consumed by the export.
+//! Any other [RecordBatchReader] can be exported with [`reader_into_pyarrow`].
+//!
+//! A `Vec<T>` converts to and from a Python list of the conversions of `T`, so
+//! `Vec<i32>` is a list of int32 scalars. `Vec<u8>` is the exception: it is a
+//! single binary scalar.
+//!
+//! [PyArrowType] wraps these for use as `#[pyfunction]` arguments and return
+//! values, through pyo3's [`FromPyObject`] and [`IntoPy`]. It also implements
+//! `TryFrom<&PyAny>`, which performs the same conversion as
+//! [`FromPyObject::extract`] for code that already holds a Python object:
+//!
+//! ```ignore
+//! let schema: PyArrowType<Schema> = obj.try_into()?;
+//! ```
+//!
+//! Objects from other libraries can be imported too, if they implement the
+//! [Arrow PyCapsule Interface](https://arrow.apache.org/docs/format/CDataInterface/PyCapsuleInterface.html):
+//! `__arrow_c_schema__` for [DataType], [Field] and [Schema], `__arrow_c_array__`
+//! for [ArrayData] and [RecordBatch], and `__arrow_c_stream__` for readers.
+//! Other objects must be instances of the pyarrow classes above. When exporting,
+//! the PyCapsule Interface is used if the installed pyarrow supports it.
+//!
+//! Schema and field metadata, including extension type annotations, is preserved
+//! by every conversion that has a field or schema to hold it. An array has no
+//! field of its own, so a `pyarrow.Array` of an extension type converts to an
+//! array of its storage type, and the `ordered` flag of a dictionary array is
+//! only kept when it is part of a field, such as a column of a record batch.
+//!
+//! [`FromPyArrowWithSchema`] imports a stream into a `Vec<RecordBatch>` holding
+//! only the fields of a given schema, which is passed to `__arrow_c_stream__` as
+//! the requested schema so that the producer can skip the other columns.
+//!
+//! Sliced arrays are exported zero-copy, passing their offset through the C data
+//! interface. [`ToPyArrowCompacted`] exports them with the offset folded into
+//! their buffers instead, for consumers that mishandle offsets.
+//!
+//! Arrays and record batches are fully validated on import, as a buggy producer
+//! could otherwise hand out e.g. invalid UTF-8 or out of bounds offsets. Batches
+//! imported from streams, such as readers and [PyArrowTable], are not validated.
+//! [`FromPyArrowWithOptions`] takes [`ImportOptions`], which can lower the
+//! [`ValidationLevel`] of arrays from a trusted producer, where validating every
+//! value is too costly.
+//!
+//! With the `ipc` feature, `from_ipc_stream` reads data that Python serialized
+//! as an Arrow IPC stream, e.g. to move it between processes.
+//!
+//! The conversions of [DataType], [Field] and [Schema] are also available without
+//! this crate, from the `pyarrow` feature of `arrow-schema`, see
+//! [arrow_schema::pyarrow].
 
 use std::convert::{From, TryFrom};
-use std::ptr::{addr_of, addr_of_mut};
+use std::ffi::CString;
+#[cfg(feature = "ipc")]
+use std::io::Cursor;
+use std::panic::AssertUnwindSafe;
+use std::ptr::{addr_of, addr_of_mut, NonNull};
 use std::sync::Arc;
 
+use pyo3::exceptions::{PyNotImplementedError, PyTypeError, PyValueError};
 use pyo3::ffi::Py_uintptr_t;
-use pyo3::import_exception;
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyList, PyTuple};
+use pyo3::types::{PyBytes, PyCapsule, PyDict, PyList, PyTuple};
 
-use crate::array::{make_array, Array, ArrayData};
-use crate::datatypes::{DataType, Field, Schema};
+use crate::alloc::Allocation;
+use crate::array::{
+    make_array, new_empty_array, Array, ArrayData, ArrayRef, BooleanArray,
+    GenericByteArray, MutableArrayData, PrimitiveArray, StructArray,
+};
+#[cfg(feature = "ipc")]
+use crate::buffer::ScalarBuffer;
+use crate::buffer::{Buffer, MutableBuffer};
+use crate::compute::concat;
+use crate::datatypes::{
+    ArrowPrimitiveType, ByteArrayType, DataType, Field, Schema, SchemaRef,
+};
 use crate::error::ArrowError;
 use crate::ffi;
 use crate::ffi::{FFI_ArrowArray, FFI_ArrowSchema};
-use crate::ffi_stream::{
-    export_reader_into_raw, ArrowArrayStreamReader, FFI_ArrowArrayStream,
+use crate::ffi_stream::{ArrowArrayStreamReader, FFI_ArrowArrayStream};
+#[cfg(feature = "ipc")]
+use crate::ipc::reader::StreamReader;
+use crate::record_batch::{
+    RecordBatch, RecordBatchIterator, RecordBatchOptions, RecordBatchReader,
+};
+use arrow_schema::pyarrow::{
+    data_type_from_pyarrow, data_type_to_pyarrow, field_from_pyarrow, field_to_pyarrow,
+    schema_from_pyarrow, schema_to_pyarrow, take_from_capsule, validate_class,
 };
-use crate::record_batch::RecordBatch;
 
-import_exception!(pyarrow, ArrowException);
-pub type PyArrowException = ArrowException;
+pub use arrow_schema::pyarrow::{to_py_err, PyArrowException};
 
-fn to_py_err(err: ArrowError) -> PyErr {
-    PyArrowException::new_err(err.to_string())
+/// Imports the result of `value.__arrow_c_array__()`.
+fn array_from_capsules(value: &PyAny) -> PyResult<(FFI_ArrowArray, FFI_ArrowSchema)> {
+    let capsules = value.call_method0("__arrow_c_array__")?;
+    let capsules = capsules.downcast::<PyTuple>()?;
+    let schema = unsafe {
+        take_from_capsule(
+            capsules.get_item(0)?,
+            "arrow_schema",
+            FFI_ArrowSchema::empty(),
+        )?
+    };
+    let array = unsafe {
+        take_from_capsule(
+            capsules.get_item(1)?,
+            "arrow_array",
+            FFI_ArrowArray::empty(),
+        )?
+    };
+    Ok((array, schema))
 }
 
-pub trait PyArrowConvert: Sized {
+/// Trait for converting Python objects to arrow-rs types.
+pub trait FromPyArrow: Sized {
     fn from_pyarrow(value: &PyAny) -> PyResult<Self>;
+}
+
+/// Trait for converting Python objects to arrow-rs types with a given schema,
+/// letting producers of `__arrow_c_stream__` push down the projection.
+pub trait FromPyArrowWithSchema: Sized {
+    fn from_pyarrow_with_schema(value: &PyAny, schema: &Schema) -> PyResult<Self>;
+}
+
+/// Create a new PyArrow object from a arrow-rs type.
+pub trait ToPyArrow {
     fn to_pyarrow(&self, py: Python) -> PyResult<PyObject>;
 }
 
-impl PyArrowConvert for DataType {
+/// Convert a arrow-rs type into a PyArrow object.
+pub trait IntoPyArrow {
+    fn into_pyarrow(self, py: Python) -> PyResult<PyObject>;
+}
+
+impl<T: ToPyArrow> IntoPyArrow for T {
+    fn into_pyarrow(self, py: Python) -> PyResult<PyObject> {
+        self.to_pyarrow(py)
+    }
+}
+
+impl FromPyArrow for DataType {
     fn from_pyarrow(value: &PyAny) -> PyResult<Self> {
-        let c_schema = FFI_ArrowSchema::empty();
-        let c_schema_ptr = &c_schema as *const FFI_ArrowSchema;
-        value.call_method1("_export_to_c", (c_schema_ptr as Py_uintptr_t,))?;
-        let dtype = DataType::try_from(&c_schema).map_err(to_py_err)?;
-        Ok(dtype)
+        data_type_from_pyarrow(value)
     }
+}
 
+impl ToPyArrow for DataType {
     fn to_pyarrow(&self, py: Python) -> PyResult<PyObject> {
-        let c_schema = FFI_ArrowSchema::try_from(self).map_err(to_py_err)?;
-        let c_schema_ptr = &c_schema as *const FFI_ArrowSchema;
-        let module = py.import("pyarrow")?;
-        let class = module.getattr("DataType")?;
-        let dtype =
-            class.call_method1("_import_from_c", (c_schema_ptr as Py_uintptr_t,))?;
-        Ok(dtype.into())
+        data_type_to_pyarrow(py, self)
     }
 }
 
-impl PyArrowConvert for Field {
+impl FromPyArrow for Field {
     fn from_pyarrow(value: &PyAny) -> PyResult<Self> {
-        let c_schema = FFI_ArrowSchema::empty();
-        let c_schema_ptr = &c_schema as *const FFI_ArrowSchema;
-        value.call_method1("_export_to_c", (c_schema_ptr as Py_uintptr_t,))?;
-        let field = Field::try_from(&c_schema).map_err(to_py_err)?;
-        Ok(field)
+        field_from_pyarrow(value)
     }
+}
 
+impl ToPyArrow for Field {
     fn to_pyarrow(&self, py: Python) -> PyResult<PyObject> {
-        let c_schema = FFI_ArrowSchema::try_from(self).map_err(to_py_err)?;
-        let c_schema_ptr = &c_schema as *const FFI_ArrowSchema;
-        let module = py.import("pyarrow")?;
-        let class = module.getattr("Field")?;
-        let dtype =
-            class.call_method1("_import_from_c", (c_schema_ptr as Py_uintptr_t,))?;
-        Ok(dtype.into())
+        field_to_pyarrow(py, self)
     }
 }
 
-impl PyArrowConvert for Schema {
+impl FromPyArrow for Schema {
     fn from_pyarrow(value: &PyAny) -> PyResult<Self> {
-        let c_schema = FFI_ArrowSchema::empty();
-        let c_schema_ptr = &c_schema as *const FFI_ArrowSchema;
-        value.call_method1("_export_to_c", (c_schema_ptr as Py_uintptr_t,))?;
-        let schema = Schema::try_from(&c_schema).map_err(to_py_err)?;
-        Ok(schema)
+        schema_from_pyarrow(value)
     }
+}
 
+impl ToPyArrow for Schema {
     fn to_pyarrow(&self, py: Python) -> PyResult<PyObject> {
-        let c_schema = FFI_ArrowSchema::try_from(self).map_err(to_py_err)?;
-        let c_schema_ptr = &c_schema as *const FFI_ArrowSchema;
-        let module = py.import("pyarrow")?;
-        let class = module.getattr("Schema")?;
-        let schema =
-            class.call_method1("_import_from_c", (c_schema_ptr as Py_uintptr_t,))?;
-        Ok(schema.into())
+        schema_to_pyarrow(py, self)
+    }
+}
+
+/// How thoroughly [FromPyArrowWithOptions] validates an imported array.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ValidationLevel {
+    /// Validate the layout and every value, such as that strings are valid
+    /// UTF-8 and offsets are monotonic, see [ArrayData::validate_full].
+    #[default]
+    Full,
+    /// Only validate the layout, such as the number and lengths of buffers, see
+    /// [ArrayData::validate].
+    Structural,
+    /// Skip validation, for arrays from a trusted producer. Missing buffers are
+    /// still rejected.
+    None,
+}
+
+/// Options for importing arrays with [FromPyArrowWithOptions].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ImportOptions {
+    validation: ValidationLevel,
+}
+
+impl ImportOptions {
+    /// Sets how thoroughly to validate the imported array, [ValidationLevel::Full]
+    /// by default.
+    ///
+    /// # Safety
+    ///
+    /// With any level other than [ValidationLevel::Full], the caller must ensure
+    /// that the imported array is valid, see [ArrayData::new_unchecked].
+    pub unsafe fn with_validation(mut self, validation: ValidationLevel) -> Self {
+        self.validation = validation;
+        self
+    }
+
+    /// Returns how thoroughly the imported array is validated.
+    pub fn validation(&self) -> ValidationLevel {
+        self.validation
     }
 }
 
-impl PyArrowConvert for ArrayData {
+/// Trait for converting Python objects to arrow-rs types with [ImportOptions].
+pub trait FromPyArrowWithOptions: Sized {
+    fn from_pyarrow_with_options(value: &PyAny, options: ImportOptions)
+        -> PyResult<Self>;
+}
+
+impl FromPyArrow for ArrayData {
     fn from_pyarrow(value: &PyAny) -> PyResult<Self> {
-        // prepare a pointer to receive the Array struct
-        let mut array = FFI_ArrowArray::empty();
-        let mut schema = FFI_ArrowSchema::empty();
+        Self::from_pyarrow_with_options(value, ImportOptions::default())
+    }
+}
 
-        // make the conversion through PyArrow's private API
-        // this changes the pointer's memory and is thus unsafe.
-        // In particular, `_export_to_c` can go out of bounds
-        value.call_method1(
-            "_export_to_c",
-            (
-                addr_of_mut!(array) as Py_uintptr_t,
-                addr_of_mut!(schema) as Py_uintptr_t,
-            ),
-        )?;
+impl FromPyArrowWithOptions for ArrayData {
+    fn from_pyarrow_with_options(
+        value: &PyAny,
+        options: ImportOptions,
+    ) -> PyResult<Self> {
+        let data = import_array_data(value)?;
+        match options.validation {
+            ValidationLevel::Full => data.validate_full(),
+            ValidationLevel::Structural => data.validate(),
+            ValidationLevel::None => Ok(()),
+        }
+        .map_err(to_py_err)?;
+        Ok(data)
+    }
+}
 
+/// Imports a `pyarrow.Array`, or an object implementing `__arrow_c_array__`,
+/// without validating it.
+fn import_array_data(value: &PyAny) -> PyResult<ArrayData> {
+    if value.hasattr("__arrow_c_array__")? {
+        let (array, schema) = array_from_capsules(value)?;
         let ffi_array = ffi::ArrowArray::new(array, schema);
-        let data = ArrayData::try_from(ffi_array).map_err(to_py_err)?;
+        return ArrayData::try_from(ffi_array).map_err(to_py_err);
+    }
+    validate_class("Array", value)?;
 
-        Ok(data)
+    // prepare a pointer to receive the Array struct
+    let mut array = FFI_ArrowArray::empty();
+    let mut schema = FFI_ArrowSchema::empty();
+
+    // make the conversion through PyArrow's private API
+    // this changes the pointer's memory and is thus unsafe.
+    // In particular, `_export_to_c` can go out of bounds
+    value.call_method1(
+        "_export_to_c",
+        (
+            addr_of_mut!(array) as Py_uintptr_t,
+            addr_of_mut!(schema) as Py_uintptr_t,
+        ),
+    )?;
+
+    let ffi_array = ffi::ArrowArray::new(array, schema);
+    let data = ArrayData::try_from(ffi_array).map_err(to_py_err)?;
+
+    Ok(data)
+}
+
+impl FromPyArrowWithOptions for ArrayRef {
+    fn from_pyarrow_with_options(
+        value: &PyAny,
+        options: ImportOptions,
+    ) -> PyResult<Self> {
+        Ok(make_array(ArrayData::from_pyarrow_with_options(
+            value, options,
+        )?))
     }
+}
 
+impl ToPyArrow for ArrayData {
     fn to_pyarrow(&self, py: Python) -> PyResult<PyObject> {
         let array = FFI_ArrowArray::new(self);
         let schema = FFI_ArrowSchema::try_from(self.data_type()).map_err(to_py_err)?;
 
         let module = py.import("pyarrow")?;
         let class = module.getattr("Array")?;
+        if class.hasattr("_import_from_c_capsule")? {
+            let schema = PyCapsule::new(py, schema, Some(CString::new("arrow_schema")?))?;
+            let array = PyCapsule::new(py, array, Some(CString::new("arrow_array")?))?;
+            let array = class.call_method1("_import_from_c_capsule", (schema, array))?;
+            return Ok(array.to_object(py));
+        }
+
         let array = class.call_method1(
             "_import_from_c",
             (
@@ -149,12 +363,260 @@ impl PyArrowConvert for ArrayData {
     }
 }
 
-impl<T: PyArrowConvert> PyArrowConvert for Vec<T> {
+/// Export an [ArrayData] to pyarrow with all offsets folded into its buffers.
+///
+/// [ToPyArrow] exports sliced arrays zero-copy by passing their offset through
+/// the C data interface, which older pyarrow releases mishandle for some nested
+/// types. This instead exports an equivalent array whose offset is zero at every
+/// level, re-slicing buffers where possible and copying only when the offset
+/// cannot be represented by a buffer slice, e.g. a bitmap starting mid-byte.
+///
+/// Sliced `RunEndEncoded` arrays, at any level, are not supported yet and
+/// raise `pyarrow.ArrowNotImplementedError`.
+pub trait ToPyArrowCompacted {
+    fn to_pyarrow_compacted(&self, py: Python) -> PyResult<PyObject>;
+}
+
+impl ToPyArrowCompacted for ArrayData {
+    fn to_pyarrow_compacted(&self, py: Python) -> PyResult<PyObject> {
+        compact_array_data(self).map_err(to_py_err)?.to_pyarrow(py)
+    }
+}
+
+/// Returns an [ArrayData] equal to `data` whose offset, and the offset of each of
+/// its descendants, is zero
+fn compact_array_data(data: &ArrayData) -> Result<ArrayData, ArrowError> {
+    let offset = data.offset();
+    let len = data.len();
+
+    let mut child_data = data.child_data().to_vec();
+    let buffers = match data.data_type() {
+        _ if offset == 0 => data.buffers().to_vec(),
+        DataType::Boolean => vec![data.buffers()[0].bit_slice(offset, len)],
+        DataType::Utf8 | DataType::Binary | DataType::List(_) | DataType::Map(_, _) => {
+            let offsets = data.buffers()[0].slice_with_length(offset * 4, (len + 1) * 4);
+            std::iter::once(offsets)
+                .chain(data.buffers().iter().skip(1).cloned())
+                .collect()
+        }
+        DataType::LargeUtf8 | DataType::LargeBinary | DataType::LargeList(_) => {
+            let offsets = data.buffers()[0].slice_with_length(offset * 8, (len + 1) * 8);
+            std::iter::once(offsets)
+                .chain(data.buffers().iter().skip(1).cloned())
+                .collect()
+        }
+        // Children of a struct are sliced along with their parent, including
+        // on import, where the offset of a struct is pushed down to its children
+        DataType::Struct(_) => vec![],
+        DataType::FixedSizeList(_, size) => {
+            let size = *size as usize;
+            child_data = vec![child_data[0].slice(offset * size, len * size)];
+            vec![]
+        }
+        DataType::FixedSizeBinary(size) => {
+            let size = *size as usize;
+            vec![data.buffers()[0].slice_with_length(offset * size, len * size)]
+        }
+        DataType::RunEndEncoded(_, _) => {
+            return Err(ArrowError::NotYetImplemented(
+                "Compacting a sliced RunEndEncoded array".to_string(),
+            ))
+        }
+        DataType::Dictionary(key, _) => {
+            let width = key.primitive_width().ok_or_else(|| {
+                ArrowError::InvalidArgumentError(format!(
+                    "Dictionary key type {key} has no fixed width"
+                ))
+            })?;
+            vec![data.buffers()[0].slice_with_length(offset * width, len * width)]
+        }
+        d => match d.primitive_width() {
+            Some(width) => {
+                vec![data.buffers()[0].slice_with_length(offset * width, len * width)]
+            }
+            // Fall back to copying the sliced values
+            None => {
+                let mut mutable = MutableArrayData::new(vec![data], false, len);
+                mutable.extend(0, 0, len);
+                return compact_array_data(&mutable.freeze());
+            }
+        },
+    };
+
+    let child_data = child_data
+        .iter()
+        .map(compact_array_data)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    ArrayData::builder(data.data_type().clone())
+        .len(len)
+        .nulls(data.nulls().cloned())
+        .buffers(buffers)
+        .child_data(child_data)
+        .build()
+}
+
+impl FromPyArrow for ArrayRef {
+    fn from_pyarrow(value: &PyAny) -> PyResult<Self> {
+        Ok(make_array(ArrayData::from_pyarrow(value)?))
+    }
+}
+
+impl ToPyArrow for dyn Array {
+    fn to_pyarrow(&self, py: Python) -> PyResult<PyObject> {
+        self.to_data().to_pyarrow(py)
+    }
+}
+
+impl ToPyArrow for ArrayRef {
+    fn to_pyarrow(&self, py: Python) -> PyResult<PyObject> {
+        self.as_ref().to_pyarrow(py)
+    }
+}
+
+/// Imports an array and downcasts it to `A`, returning a `TypeError` naming
+/// both data types if the imported array has a different type.
+fn downcast_from_pyarrow<A: Array + Clone + 'static>(
+    value: &PyAny,
+    expected: &DataType,
+) -> PyResult<A> {
+    let array = ArrayRef::from_pyarrow(value)?;
+    array.as_any().downcast_ref::<A>().cloned().ok_or_else(|| {
+        PyTypeError::new_err(format!(
+            "Expected array of type {expected}, got {}",
+            array.data_type()
+        ))
+    })
+}
+
+impl<T: ArrowPrimitiveType> FromPyArrow for PrimitiveArray<T> {
+    fn from_pyarrow(value: &PyAny) -> PyResult<Self> {
+        downcast_from_pyarrow(value, &T::DATA_TYPE)
+    }
+}
+
+impl<T: ArrowPrimitiveType> ToPyArrow for PrimitiveArray<T> {
+    fn to_pyarrow(&self, py: Python) -> PyResult<PyObject> {
+        self.to_data().to_pyarrow(py)
+    }
+}
+
+impl<T: ByteArrayType> FromPyArrow for GenericByteArray<T> {
+    fn from_pyarrow(value: &PyAny) -> PyResult<Self> {
+        downcast_from_pyarrow(value, &T::DATA_TYPE)
+    }
+}
+
+impl<T: ByteArrayType> ToPyArrow for GenericByteArray<T> {
+    fn to_pyarrow(&self, py: Python) -> PyResult<PyObject> {
+        self.to_data().to_pyarrow(py)
+    }
+}
+
+impl FromPyArrow for BooleanArray {
+    fn from_pyarrow(value: &PyAny) -> PyResult<Self> {
+        downcast_from_pyarrow(value, &DataType::Boolean)
+    }
+}
+
+impl ToPyArrow for BooleanArray {
+    fn to_pyarrow(&self, py: Python) -> PyResult<PyObject> {
+        self.to_data().to_pyarrow(py)
+    }
+}
+
+/// A `pyarrow.ChunkedArray`, held as its chunks and their common data type.
+///
+/// The data type is stored separately so that a chunked array without any
+/// chunks keeps it.
+#[derive(Debug, Clone)]
+pub struct PyArrowChunkedArray {
+    chunks: Vec<ArrayRef>,
+    data_type: DataType,
+}
+
+impl PyArrowChunkedArray {
+    /// Creates a new chunked array, returning an error if any of `chunks` is
+    /// not of type `data_type`.
+    pub fn try_new(
+        chunks: Vec<ArrayRef>,
+        data_type: DataType,
+    ) -> Result<Self, ArrowError> {
+        for chunk in &chunks {
+            if chunk.data_type() != &data_type {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Expected chunk of type {data_type}, got {}",
+                    chunk.data_type()
+                )));
+            }
+        }
+        Ok(Self { chunks, data_type })
+    }
+
+    /// Returns the chunks of this chunked array.
+    pub fn chunks(&self) -> &[ArrayRef] {
+        &self.chunks
+    }
+
+    /// Returns the data type of this chunked array.
+    pub fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    /// Consumes this chunked array, returning its chunks and data type.
+    pub fn into_inner(self) -> (Vec<ArrayRef>, DataType) {
+        (self.chunks, self.data_type)
+    }
+
+    /// Concatenates the chunks into a single array.
+    pub fn concat(&self) -> Result<ArrayRef, ArrowError> {
+        if self.chunks.is_empty() {
+            return Ok(new_empty_array(&self.data_type));
+        }
+        let chunks: Vec<&dyn Array> = self.chunks.iter().map(|c| c.as_ref()).collect();
+        concat(&chunks)
+    }
+}
+
+impl FromPyArrow for PyArrowChunkedArray {
+    fn from_pyarrow(value: &PyAny) -> PyResult<Self> {
+        validate_class("ChunkedArray", value)?;
+
+        let data_type = DataType::from_pyarrow(value.getattr("type")?)?;
+        let chunks = value
+            .getattr("chunks")?
+            .iter()?
+            .map(|chunk| ArrayRef::from_pyarrow(chunk?))
+            .collect::<PyResult<_>>()?;
+        Self::try_new(chunks, data_type).map_err(to_py_err)
+    }
+}
+
+impl ToPyArrow for PyArrowChunkedArray {
+    fn to_pyarrow(&self, py: Python) -> PyResult<PyObject> {
+        let chunks = self
+            .chunks
+            .iter()
+            .map(|chunk| chunk.to_pyarrow(py))
+            .collect::<PyResult<Vec<_>>>()?;
+        let data_type = self.data_type.to_pyarrow(py)?;
+
+        let module = py.import("pyarrow")?;
+        let kwargs = PyDict::new(py);
+        kwargs.set_item("type", data_type)?;
+        let array = module.call_method("chunked_array", (chunks,), Some(kwargs))?;
+        Ok(PyObject::from(array))
+    }
+}
+
+impl<T: FromPyArrow> FromPyArrow for Vec<T> {
     fn from_pyarrow(value: &PyAny) -> PyResult<Self> {
         let list = value.downcast::<PyList>()?;
         list.iter().map(|x| T::from_pyarrow(&x)).collect()
     }
+}
 
+impl<T: ToPyArrow> ToPyArrow for Vec<T> {
     fn to_pyarrow(&self, py: Python) -> PyResult<PyObject> {
         let values = self
             .iter()
@@ -164,8 +626,154 @@ impl<T: PyArrowConvert> PyArrowConvert for Vec<T> {
     }
 }
 
-impl PyArrowConvert for RecordBatch {
+/// Checks that `value` is a valid `pyarrow.Scalar` of type `pyarrow.{expected}()`.
+fn validate_scalar(value: &PyAny, expected: &str) -> PyResult<()> {
+    validate_class("Scalar", value)?;
+    let expected = value.py().import("pyarrow")?.call_method0(expected)?;
+    let found = value.getattr("type")?;
+    if !found.eq(expected)? {
+        return Err(PyTypeError::new_err(format!(
+            "Expected scalar of type {expected}, got {found}"
+        )));
+    }
+    if !value.getattr("is_valid")?.extract::<bool>()? {
+        return Err(PyValueError::new_err(format!(
+            "Expected non-null scalar of type {expected}"
+        )));
+    }
+    Ok(())
+}
+
+/// Creates a `pyarrow.Scalar` of type `pyarrow.{data_type}()` from `value`.
+fn scalar_to_pyarrow(py: Python, value: PyObject, data_type: &str) -> PyResult<PyObject> {
+    let module = py.import("pyarrow")?;
+    let kwargs = PyDict::new(py);
+    kwargs.set_item("type", module.call_method0(data_type)?)?;
+    let scalar = module.call_method("scalar", (value,), Some(kwargs))?;
+    Ok(scalar.to_object(py))
+}
+
+macro_rules! scalar_conversions {
+    ($t:ty, $data_type:literal) => {
+        impl FromPyArrow for $t {
+            fn from_pyarrow(value: &PyAny) -> PyResult<Self> {
+                validate_scalar(value, $data_type)?;
+                value.call_method0("as_py")?.extract()
+            }
+        }
+
+        impl ToPyArrow for $t {
+            fn to_pyarrow(&self, py: Python) -> PyResult<PyObject> {
+                scalar_to_pyarrow(py, self.to_object(py), $data_type)
+            }
+        }
+    };
+}
+
+scalar_conversions!(bool, "bool_");
+scalar_conversions!(i32, "int32");
+scalar_conversions!(i64, "int64");
+scalar_conversions!(f32, "float32");
+scalar_conversions!(f64, "float64");
+scalar_conversions!(String, "string");
+
+/// Converts a binary `pyarrow.Scalar` into its bytes.
+///
+/// Unlike other `Vec<T>`, which are converted from a list of scalars, a
+/// `Vec<u8>` is a single scalar.
+impl FromPyArrow for Vec<u8> {
+    fn from_pyarrow(value: &PyAny) -> PyResult<Self> {
+        validate_scalar(value, "binary")?;
+        let bytes = value.call_method0("as_py")?;
+        Ok(bytes.downcast::<PyBytes>()?.as_bytes().to_vec())
+    }
+}
+
+/// Creates a binary `pyarrow.Scalar` holding these bytes.
+///
+/// Unlike other `Vec<T>`, which are converted to a list of scalars, a
+/// `Vec<u8>` is a single scalar.
+impl ToPyArrow for Vec<u8> {
+    fn to_pyarrow(&self, py: Python) -> PyResult<PyObject> {
+        scalar_to_pyarrow(py, PyBytes::new(py, self).into(), "binary")
+    }
+}
+
+/// A `pyarrow.Scalar` of any type, held as an array of length one.
+///
+/// This is the form arrow-rs kernels accept for scalar arguments. Importing a
+/// scalar copies its value into a new array, whereas exporting shares the array
+/// with pyarrow, which returns its only element.
+#[derive(Debug, Clone)]
+pub struct PyArrowScalar {
+    array: ArrayRef,
+}
+
+impl PyArrowScalar {
+    /// Creates a new scalar, returning an error if `array` is not of length one.
+    pub fn try_new(array: ArrayRef) -> Result<Self, ArrowError> {
+        if array.len() != 1 {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Expected array of length 1 for a scalar, got {}",
+                array.len()
+            )));
+        }
+        Ok(Self { array })
+    }
+
+    /// Returns the array of length one holding this scalar.
+    pub fn array(&self) -> &ArrayRef {
+        &self.array
+    }
+
+    /// Consumes this scalar, returning the array of length one holding it.
+    pub fn into_inner(self) -> ArrayRef {
+        self.array
+    }
+}
+
+impl FromPyArrow for PyArrowScalar {
     fn from_pyarrow(value: &PyAny) -> PyResult<Self> {
+        validate_class("Scalar", value)?;
+
+        let module = value.py().import("pyarrow")?;
+        let array = module.call_method1("repeat", (value, 1))?;
+        Self::try_new(ArrayRef::from_pyarrow(array)?).map_err(to_py_err)
+    }
+}
+
+impl ToPyArrow for PyArrowScalar {
+    fn to_pyarrow(&self, py: Python) -> PyResult<PyObject> {
+        let array = self.array.to_pyarrow(py)?;
+        let scalar = array.as_ref(py).get_item(0)?;
+        Ok(scalar.to_object(py))
+    }
+}
+
+impl FromPyArrow for RecordBatch {
+    fn from_pyarrow(value: &PyAny) -> PyResult<Self> {
+        if value.hasattr("__arrow_c_array__")? {
+            let (array, c_schema) = array_from_capsules(value)?;
+            // the struct array does not carry the schema level metadata
+            let schema = Schema::try_from(&c_schema).map_err(to_py_err)?;
+            let ffi_array = ffi::ArrowArray::new(array, c_schema);
+            let data = ArrayData::try_from(ffi_array).map_err(to_py_err)?;
+            data.validate_full().map_err(to_py_err)?;
+            let array = StructArray::from(data);
+            if array.null_count() != 0 {
+                return Err(PyValueError::new_err(
+                    "Cannot import a RecordBatch from a StructArray with top-level nulls",
+                ));
+            }
+            let options = RecordBatchOptions::new().with_row_count(Some(array.len()));
+            let (_, columns, _) = array.into_parts();
+            let batch =
+                RecordBatch::try_new_with_options(Arc::new(schema), columns, &options)
+                    .map_err(to_py_err)?;
+            return Ok(batch);
+        }
+        validate_class("RecordBatch", value)?;
+
         // TODO(kszucs): implement the FFI conversions in arrow-rs for RecordBatches
         let schema = value.getattr("schema")?;
         let schema = Arc::new(Schema::from_pyarrow(schema)?);
@@ -179,84 +787,394 @@ impl PyArrowConvert for RecordBatch {
         let batch = RecordBatch::try_new(schema, arrays).map_err(to_py_err)?;
         Ok(batch)
     }
+}
 
+impl ToPyArrow for RecordBatch {
     fn to_pyarrow(&self, py: Python) -> PyResult<PyObject> {
-        let mut py_arrays = vec![];
+        // Export the batch as a struct array, whose schema carries the schema
+        // level metadata
+        let schema =
+            FFI_ArrowSchema::try_from(self.schema().as_ref()).map_err(to_py_err)?;
+        let array = FFI_ArrowArray::new(&StructArray::from(self.clone()).into_data());
 
-        let schema = self.schema();
-        let columns = self.columns().iter();
+        let module = py.import("pyarrow")?;
+        let class = module.getattr("RecordBatch")?;
+        if class.hasattr("_import_from_c_capsule")? {
+            let schema = PyCapsule::new(py, schema, Some(CString::new("arrow_schema")?))?;
+            let array = PyCapsule::new(py, array, Some(CString::new("arrow_array")?))?;
+            let record = class.call_method1("_import_from_c_capsule", (schema, array))?;
+            return Ok(PyObject::from(record));
+        }
+
+        let record = class.call_method1(
+            "_import_from_c",
+            (
+                addr_of!(array) as Py_uintptr_t,
+                addr_of!(schema) as Py_uintptr_t,
+            ),
+        )?;
+        Ok(PyObject::from(record))
+    }
+}
 
-        for array in columns {
-            py_arrays.push(array.to_data().to_pyarrow(py)?);
+/// Imports a `pyarrow.Buffer`, or any object implementing the Python buffer
+/// protocol such as `bytes`, `memoryview` or a numpy array, without copying its
+/// contents. Non-contiguous buffers are copied.
+///
+/// The returned [`Buffer`] holds a strong reference to the Python object, which
+/// keeps the underlying memory alive. Releasing that reference requires the GIL:
+/// if the [`Buffer`] is dropped on a thread that does not hold it, pyo3 defers the
+/// decrement until the GIL is next acquired, so the memory may outlive the
+/// [`Buffer`] for a while.
+impl FromPyArrow for Buffer {
+    fn from_pyarrow(value: &PyAny) -> PyResult<Self> {
+        let pyarrow = PyModule::import(value.py(), "pyarrow")?;
+        let value = if value.is_instance(pyarrow.getattr("Buffer")?)? {
+            value
+        } else {
+            // Raises a TypeError for objects without the buffer protocol
+            let view = PyModule::import(value.py(), "builtins")?
+                .getattr("memoryview")?
+                .call1((value,))?;
+            if view.getattr("contiguous")?.extract::<bool>()? {
+                pyarrow.call_method1("py_buffer", (value,))?
+            } else {
+                pyarrow.call_method1("py_buffer", (view.call_method0("tobytes")?,))?
+            }
+        };
+
+        if !value.getattr("is_cpu")?.extract::<bool>()? {
+            return Err(PyValueError::new_err("Expected a buffer in CPU memory"));
         }
+        let address = value.getattr("address")?.extract::<usize>()?;
+        let size = value.getattr("size")?.extract::<usize>()?;
 
-        let py_schema = schema.to_pyarrow(py)?;
+        match NonNull::new(address as *mut u8) {
+            Some(ptr) => {
+                // Dropping a `Py` never unwinds into the Python interpreter
+                let owner = AssertUnwindSafe(PyObject::from(value));
+                let owner: Arc<dyn Allocation> = Arc::new(owner);
+                // Safety: pyarrow guarantees `size` bytes are valid at `address`
+                // for as long as the buffer object, kept alive by `owner`, exists
+                Ok(unsafe { Buffer::from_custom_allocation(ptr, size, owner) })
+            }
+            None if size == 0 => Ok(Buffer::from(MutableBuffer::new(0))),
+            None => Err(PyValueError::new_err("Buffer has a null address")),
+        }
+    }
+}
 
-        let module = py.import("pyarrow")?;
-        let class = module.getattr("RecordBatch")?;
-        let args = (py_arrays,);
-        let kwargs = PyDict::new(py);
-        kwargs.set_item("schema", py_schema)?;
-        let record = class.call_method("from_arrays", args, Some(kwargs))?;
+/// Exports a [`Buffer`] as a `pyarrow.Buffer` without copying its contents.
+///
+/// A clone of the [`Buffer`] is kept in a `PyCapsule` passed as the `base` of
+/// `pyarrow.foreign_buffer`, and is only dropped once Python garbage collects
+/// the returned object.
+impl ToPyArrow for Buffer {
+    fn to_pyarrow(&self, py: Python) -> PyResult<PyObject> {
+        let base = PyCapsule::new(py, self.clone(), None)?;
 
-        Ok(PyObject::from(record))
+        let module = py.import("pyarrow")?;
+        let buffer = module.call_method1(
+            "foreign_buffer",
+            (self.as_ptr() as Py_uintptr_t, self.len(), base),
+        )?;
+        Ok(PyObject::from(buffer))
     }
 }
 
-impl PyArrowConvert for ArrowArrayStreamReader {
+impl FromPyArrow for ArrowArrayStreamReader {
     fn from_pyarrow(value: &PyAny) -> PyResult<Self> {
-        // prepare a pointer to receive the stream struct
-        let stream = Box::new(FFI_ArrowArrayStream::empty());
-        let stream_ptr = Box::into_raw(stream) as *mut FFI_ArrowArrayStream;
+        if value.hasattr("__arrow_c_stream__")? {
+            let capsule = value.call_method0("__arrow_c_stream__")?;
+            let stream = unsafe {
+                take_from_capsule(
+                    capsule,
+                    "arrow_array_stream",
+                    FFI_ArrowArrayStream::empty(),
+                )?
+            };
+            return ArrowArrayStreamReader::try_new(stream).map_err(to_py_err);
+        }
+        validate_class("RecordBatchReader", value)?;
+
+        // prepare a struct to receive the stream, which is released on drop if
+        // anything below fails
+        let mut stream = FFI_ArrowArrayStream::empty();
 
         // make the conversion through PyArrow's private API
         // this changes the pointer's memory and is thus unsafe.
         // In particular, `_export_to_c` can go out of bounds
-        let args = PyTuple::new(value.py(), &[stream_ptr as Py_uintptr_t]);
+        let args = PyTuple::new(value.py(), &[addr_of_mut!(stream) as Py_uintptr_t]);
         value.call_method1("_export_to_c", args)?;
 
-        let stream_reader =
-            unsafe { ArrowArrayStreamReader::from_raw(stream_ptr).unwrap() };
+        ArrowArrayStreamReader::try_new(stream).map_err(to_py_err)
+    }
+}
+
+impl FromPyArrowWithSchema for Vec<RecordBatch> {
+    /// Reads every batch of a stream, keeping only the fields of `schema`.
+    ///
+    /// `schema` is passed to `__arrow_c_stream__` as the requested schema. If the
+    /// producer rejects it with a `TypeError` or `NotImplementedError`, or returns
+    /// a stream with a different schema, the fields of `schema` are selected by
+    /// name after import, raising a `TypeError` if a selected field differs from
+    /// `schema` in type or nullability. Any other error from the producer is
+    /// returned.
+    fn from_pyarrow_with_schema(value: &PyAny, schema: &Schema) -> PyResult<Self> {
+        let reader = if value.hasattr("__arrow_c_stream__")? {
+            let requested = FFI_ArrowSchema::try_from(schema).map_err(to_py_err)?;
+            let requested = PyCapsule::new(
+                value.py(),
+                requested,
+                Some(CString::new("arrow_schema")?),
+            )?;
+            let capsule = match value.call_method1("__arrow_c_stream__", (requested,)) {
+                Ok(capsule) => capsule,
+                // The producer does not support the requested schema
+                Err(e)
+                    if e.is_instance_of::<PyTypeError>(value.py())
+                        || e.is_instance_of::<PyNotImplementedError>(value.py()) =>
+                {
+                    value.call_method0("__arrow_c_stream__")?
+                }
+                Err(e) => return Err(e),
+            };
+            let stream = unsafe {
+                take_from_capsule(
+                    capsule,
+                    "arrow_array_stream",
+                    FFI_ArrowArrayStream::empty(),
+                )?
+            };
+            ArrowArrayStreamReader::try_new(stream).map_err(to_py_err)?
+        } else {
+            ArrowArrayStreamReader::from_pyarrow(value)?
+        };
 
-        unsafe {
-            drop(Box::from_raw(stream_ptr));
+        let stream_schema = reader.schema();
+        if stream_schema.fields() == schema.fields() {
+            return reader.collect::<Result<_, _>>().map_err(to_py_err);
         }
 
-        Ok(stream_reader)
+        let indices = schema
+            .fields()
+            .iter()
+            .map(|f| stream_schema.index_of(f.name()))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(to_py_err)?;
+        for (expected, index) in schema.fields().iter().zip(&indices) {
+            let found = stream_schema.field(*index);
+            if found.data_type() != expected.data_type()
+                || found.is_nullable() != expected.is_nullable()
+            {
+                return Err(PyTypeError::new_err(format!(
+                    "Expected field '{}' of type {} (nullable: {}), got {} (nullable: {})",
+                    expected.name(),
+                    expected.data_type(),
+                    expected.is_nullable(),
+                    found.data_type(),
+                    found.is_nullable(),
+                )));
+            }
+        }
+
+        let batches = reader.collect::<Result<Vec<_>, _>>().map_err(to_py_err)?;
+        batches
+            .iter()
+            .map(|batch| batch.project(&indices))
+            .collect::<Result<_, _>>()
+            .map_err(to_py_err)
     }
+}
 
-    fn to_pyarrow(&self, py: Python) -> PyResult<PyObject> {
-        let stream = Box::new(FFI_ArrowArrayStream::empty());
-        let stream_ptr = Box::into_raw(stream) as *mut FFI_ArrowArrayStream;
+impl IntoPyArrow for ArrowArrayStreamReader {
+    fn into_pyarrow(self, py: Python) -> PyResult<PyObject> {
+        reader_into_pyarrow(py, self)
+    }
+}
 
-        unsafe { export_reader_into_raw(Box::new(self.clone()), stream_ptr) };
+impl FromPyArrow for Box<dyn RecordBatchReader + Send> {
+    fn from_pyarrow(value: &PyAny) -> PyResult<Self> {
+        let stream_reader = ArrowArrayStreamReader::from_pyarrow(value)?;
+        Ok(Box::new(stream_reader))
+    }
+}
 
+impl IntoPyArrow for Box<dyn RecordBatchReader + Send> {
+    fn into_pyarrow(self, py: Python) -> PyResult<PyObject> {
         let module = py.import("pyarrow")?;
         let class = module.getattr("RecordBatchReader")?;
-        let args = PyTuple::new(py, &[stream_ptr as Py_uintptr_t]);
+        if class.hasattr("_import_from_c_capsule")? {
+            let stream = FFI_ArrowArrayStream::new(self);
+            let name = CString::new("arrow_array_stream")?;
+            let capsule = PyCapsule::new(py, stream, Some(name))?;
+            let reader = class.call_method1("_import_from_c_capsule", (capsule,))?;
+            return Ok(PyObject::from(reader));
+        }
+
+        // pyarrow moves the stream out of this struct, which is otherwise
+        // released on drop
+        let mut stream = FFI_ArrowArrayStream::new(self);
+        let args = PyTuple::new(py, &[addr_of_mut!(stream) as Py_uintptr_t]);
         let reader = class.call_method1("_import_from_c", args)?;
         Ok(PyObject::from(reader))
     }
 }
 
-/// A newtype wrapper around a `T: PyArrowConvert` that implements
+/// Exports any [RecordBatchReader], such as an IPC `FileReader`, as a
+/// `pyarrow.RecordBatchReader` without requiring the caller to box it.
+///
+/// An error returned by `reader` is raised in Python when the failing batch is
+/// read, carrying the error's full message.
+pub fn reader_into_pyarrow<R>(py: Python, reader: R) -> PyResult<PyObject>
+where
+    R: RecordBatchReader + Send + 'static,
+{
+    let reader: Box<dyn RecordBatchReader + Send> = Box::new(reader);
+    reader.into_pyarrow(py)
+}
+
+impl<I> IntoPyArrow for RecordBatchIterator<I>
+where
+    I: IntoIterator<Item = Result<RecordBatch, ArrowError>> + 'static,
+    I::IntoIter: Send,
+{
+    fn into_pyarrow(self, py: Python) -> PyResult<PyObject> {
+        let boxed: Box<dyn RecordBatchReader + Send> = Box::new(self);
+        boxed.into_pyarrow(py)
+    }
+}
+
+/// A `pyarrow.Table`, held as the [RecordBatch]es of its chunks.
+///
+/// The schema is stored separately so that a table without any batches keeps it.
+#[derive(Debug, Clone)]
+pub struct PyArrowTable {
+    schema: SchemaRef,
+    batches: Vec<RecordBatch>,
+}
+
+impl PyArrowTable {
+    /// Creates a new table, returning an error if any of `batches` has fields
+    /// that differ from those of `schema`.
+    pub fn try_new(
+        batches: Vec<RecordBatch>,
+        schema: SchemaRef,
+    ) -> Result<Self, ArrowError> {
+        for batch in &batches {
+            if batch.schema().fields() != schema.fields() {
+                return Err(ArrowError::SchemaError(format!(
+                    "PyArrowTable schema {schema} does not match batch schema {}",
+                    batch.schema()
+                )));
+            }
+        }
+        Ok(Self { schema, batches })
+    }
+
+    /// Returns the schema of this table.
+    pub fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    /// Returns the batches of this table.
+    pub fn batches(&self) -> &[RecordBatch] {
+        &self.batches
+    }
+
+    /// Consumes this table, returning its schema and batches.
+    pub fn into_inner(self) -> (SchemaRef, Vec<RecordBatch>) {
+        (self.schema, self.batches)
+    }
+}
+
+impl FromPyArrow for PyArrowTable {
+    fn from_pyarrow(value: &PyAny) -> PyResult<Self> {
+        let reader = if value.hasattr("__arrow_c_stream__")? {
+            ArrowArrayStreamReader::from_pyarrow(value)?
+        } else {
+            validate_class("Table", value)?;
+            ArrowArrayStreamReader::from_pyarrow(value.call_method0("to_reader")?)?
+        };
+
+        let schema = reader.schema();
+        let batches = reader.collect::<Result<Vec<_>, _>>().map_err(to_py_err)?;
+        Self::try_new(batches, schema).map_err(to_py_err)
+    }
+}
+
+impl ToPyArrow for PyArrowTable {
+    fn to_pyarrow(&self, py: Python) -> PyResult<PyObject> {
+        let batches = self.batches.to_pyarrow(py)?;
+        let schema = self.schema.to_pyarrow(py)?;
+
+        let module = py.import("pyarrow")?;
+        let class = module.getattr("Table")?;
+        let kwargs = PyDict::new(py);
+        kwargs.set_item("schema", schema)?;
+        let table = class.call_method("from_batches", (batches,), Some(kwargs))?;
+        Ok(PyObject::from(table))
+    }
+}
+
+/// Reads an [Arrow IPC stream](https://arrow.apache.org/docs/format/Columnar.html#ipc-streaming-format)
+/// from Python, for data that is transported as IPC rather than through the C
+/// data interface.
+///
+/// `value` is either an object holding a serialized stream, such as `bytes` or a
+/// `pyarrow.Buffer`, which is read without copying, or a
+/// `pyarrow.RecordBatchReader`, whose batches are written one at a time to a
+/// stream with `pyarrow.ipc.new_stream` first.
+#[cfg(feature = "ipc")]
+pub fn from_ipc_stream(
+    value: &PyAny,
+) -> PyResult<StreamReader<Cursor<ScalarBuffer<u8>>>> {
+    let py = value.py();
+    let pyarrow = py.import("pyarrow")?;
+    let buffer = if value.is_instance(pyarrow.getattr("RecordBatchReader")?)? {
+        let sink = pyarrow.getattr("BufferOutputStream")?.call0()?;
+        let schema = value.getattr("schema")?;
+        let writer = py
+            .import("pyarrow.ipc")?
+            .call_method1("new_stream", (sink, schema))?;
+        for batch in value.iter()? {
+            writer.call_method1("write_batch", (batch?,))?;
+        }
+        writer.call_method0("close")?;
+        Buffer::from_pyarrow(sink.call_method0("getvalue")?)?
+    } else {
+        Buffer::from_pyarrow(value)?
+    };
+
+    StreamReader::try_new_unbuffered(Cursor::new(buffer.into()), None).map_err(to_py_err)
+}
+
+/// A newtype wrapper around a `T: FromPyArrow + IntoPyArrow` that implements
 /// [`FromPyObject`] and [`IntoPy`] allowing usage with pyo3 macros
 #[derive(Debug)]
-pub struct PyArrowType<T: PyArrowConvert>(pub T);
+pub struct PyArrowType<T: FromPyArrow + IntoPyArrow>(pub T);
 
-impl<'source, T: PyArrowConvert> FromPyObject<'source> for PyArrowType<T> {
+impl<'source, T: FromPyArrow + IntoPyArrow> FromPyObject<'source> for PyArrowType<T> {
     fn extract(value: &'source PyAny) -> PyResult<Self> {
         Ok(Self(T::from_pyarrow(value)?))
     }
 }
 
-impl<'a, T: PyArrowConvert> IntoPy<PyObject> for PyArrowType<T> {
+impl<T: FromPyArrow + IntoPyArrow> TryFrom<&PyAny> for PyArrowType<T> {
+    type Error = PyErr;
+
+    fn try_from(value: &PyAny) -> PyResult<Self> {
+        Ok(Self(T::from_pyarrow(value)?))
+    }
+}
+
+impl<T: FromPyArrow + IntoPyArrow> IntoPy<PyObject> for PyArrowType<T> {
     fn into_py(self, py: Python) -> PyObject {
-        self.0.to_pyarrow(py).unwrap()
+        self.0.into_pyarrow(py).unwrap()
     }
 }
 
-impl<T: PyArrowConvert> From<T> for PyArrowType<T> {
+impl<T: FromPyArrow + IntoPyArrow> From<T> for PyArrowType<T> {
     fn from(s: T) -> Self {
         Self(s)
     }
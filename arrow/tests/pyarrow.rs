@@ -15,28 +15,304 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use arrow::array::{ArrayRef, Int32Array, StringArray};
-use arrow::pyarrow::PyArrowConvert;
-use arrow::record_batch::RecordBatch;
+use arrow::array::{
+    Array, ArrayData, ArrayRef, BooleanArray, Int32Array, ListArray, StringArray,
+    StructArray,
+};
+use arrow::buffer::Buffer;
+use arrow::datatypes::{DataType, Field, Int32Type, Schema};
+use arrow::error::ArrowError;
+use arrow::ffi_stream::ArrowArrayStreamReader;
+use arrow::pyarrow::{
+    reader_into_pyarrow, FromPyArrow, FromPyArrowWithOptions, ImportOptions, IntoPyArrow,
+    PyArrowScalar, PyArrowTable, ToPyArrow, ToPyArrowCompacted, ValidationLevel,
+};
+use arrow::record_batch::{RecordBatch, RecordBatchIterator};
+use pyo3::types::PyList;
 use pyo3::Python;
+use std::collections::HashMap;
 use std::sync::Arc;
 
+/// Converts `value` to pyarrow and back.
+fn round_trip<T: FromPyArrow + ToPyArrow>(value: &T) -> T {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| T::from_pyarrow(value.to_pyarrow(py)?.as_ref(py))).unwrap()
+}
+
+/// Asserts that each value is unchanged by a round trip through pyarrow.
+macro_rules! assert_round_trip {
+    ($($value:expr),+ $(,)?) => {
+        $(
+            let value = $value;
+            assert_eq!(round_trip(&value), value, "{}", stringify!($value));
+        )+
+    };
+}
+
+#[test]
+fn test_round_trip() {
+    let a: ArrayRef = Arc::new(Int32Array::from(vec![Some(1), None]));
+    let b: ArrayRef = Arc::new(StringArray::from(vec![Some("a"), None]));
+    let batch = RecordBatch::try_from_iter(vec![("a", a.clone()), ("b", b)]).unwrap();
+
+    let field = Field::new("a", DataType::Int32, true)
+        .with_metadata(HashMap::from([("field_key".into(), "field_value".into())]));
+    let schema = Schema::new(vec![field.clone()]).with_metadata(HashMap::from([(
+        "schema_key".into(),
+        "schema_value".into(),
+    )]));
+    let batch_with_metadata =
+        RecordBatch::try_new(Arc::new(schema.clone()), vec![a]).unwrap();
+
+    assert_round_trip!(
+        DataType::Int32,
+        field,
+        schema,
+        batch,
+        batch_with_metadata,
+        StringArray::from(vec![Some("a"), None]),
+        true,
+        42_i32,
+        42_i64,
+        1.5_f32,
+        1.5_f64,
+        "arrow-rs".to_string(),
+        vec![0_u8, 1, 2],
+        vec![1_i32, 2],
+    );
+}
+
+#[test]
+fn test_from_pyarrow_wrong_type() {
+    pyo3::prepare_freethreaded_python();
+
+    Python::with_gil(|py| {
+        let array = StringArray::from(vec![Some("a"), None]).to_pyarrow(py)?;
+        let err = Int32Array::from_pyarrow(array.as_ref(py)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "TypeError: Expected array of type Int32, got Utf8"
+        );
+
+        let scalar = 42_i32.to_pyarrow(py)?;
+        assert!(i64::from_pyarrow(scalar.as_ref(py)).is_err());
+        Ok::<_, pyo3::PyErr>(())
+    })
+    .unwrap();
+}
+
 #[test]
-fn test_to_pyarrow() {
+fn test_table_to_pyarrow() {
     pyo3::prepare_freethreaded_python();
 
     let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2]));
-    let b: ArrayRef = Arc::new(StringArray::from(vec!["a", "b"]));
-    let input = RecordBatch::try_from_iter(vec![("a", a), ("b", b)]).unwrap();
-    println!("input: {:?}", input);
+    let batch = RecordBatch::try_from_iter(vec![("a", a)]).unwrap();
+    let schema = batch.schema();
+    let input = PyArrowTable::try_new(vec![batch.clone(), batch], schema).unwrap();
 
     let res = Python::with_gil(|py| {
         let py_input = input.to_pyarrow(py)?;
-        let records = RecordBatch::from_pyarrow(py_input.as_ref(py))?;
-        let py_records = records.to_pyarrow(py)?;
-        RecordBatch::from_pyarrow(py_records.as_ref(py))
+        PyArrowTable::from_pyarrow(py_input.as_ref(py))
     })
     .unwrap();
 
-    assert_eq!(input, res);
+    assert_eq!(res.schema(), input.schema());
+    let num_rows: usize = res.batches().iter().map(|b| b.num_rows()).sum();
+    assert_eq!(num_rows, 4);
+}
+
+#[test]
+fn test_record_batch_iterator_into_pyarrow() {
+    pyo3::prepare_freethreaded_python();
+
+    let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2]));
+    let batch = RecordBatch::try_from_iter(vec![("a", a)]).unwrap();
+    let schema = batch.schema();
+    let batches = vec![batch.clone(), batch];
+    let iter = RecordBatchIterator::new(batches.clone().into_iter().map(Ok), schema);
+
+    let res = Python::with_gil(|py| {
+        let py_reader = iter.into_pyarrow(py)?;
+        ArrowArrayStreamReader::from_pyarrow(py_reader.as_ref(py))
+    })
+    .unwrap();
+
+    let res = res.collect::<Result<Vec<_>, _>>().unwrap();
+    assert_eq!(res, batches);
+}
+
+#[test]
+fn test_sliced_to_pyarrow() {
+    pyo3::prepare_freethreaded_python();
+
+    let booleans: ArrayRef = Arc::new(BooleanArray::from(vec![
+        Some(true),
+        None,
+        Some(false),
+        Some(true),
+        None,
+        Some(true),
+        Some(false),
+        Some(false),
+        Some(true),
+    ]));
+    let strings: ArrayRef = Arc::new(StringArray::from(vec![
+        Some("a"),
+        None,
+        Some("bc"),
+        Some(""),
+        Some("def"),
+        None,
+        Some("g"),
+        Some("hi"),
+        Some("j"),
+    ]));
+    let lists: ArrayRef =
+        Arc::new(ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1)]),
+            None,
+            Some(vec![Some(2), Some(3)]),
+            Some(vec![]),
+            Some(vec![Some(4), None]),
+            None,
+            Some(vec![Some(5)]),
+            Some(vec![Some(6), Some(7)]),
+            Some(vec![]),
+        ]));
+    let structs: ArrayRef = Arc::new(StructArray::from(vec![
+        (
+            Arc::new(Field::new("a", DataType::Boolean, true)),
+            booleans.clone(),
+        ),
+        (
+            Arc::new(Field::new("b", DataType::Utf8, true)),
+            strings.clone(),
+        ),
+    ]));
+
+    for array in [booleans, strings, lists, structs] {
+        for (offset, length) in [(1, 5), (3, 5), (5, 3)] {
+            let sliced = array.to_data().slice(offset, length);
+            let (res, compacted) = Python::with_gil(|py| {
+                let res = ArrayData::from_pyarrow(sliced.to_pyarrow(py)?.as_ref(py))?;
+                let compacted = sliced.to_pyarrow_compacted(py)?;
+                let compacted = ArrayData::from_pyarrow(compacted.as_ref(py))?;
+                Ok::<_, pyo3::PyErr>((res, compacted))
+            })
+            .unwrap();
+
+            assert_eq!(res, sliced);
+            assert_eq!(compacted, sliced);
+            assert_eq!(compacted.offset(), 0);
+        }
+    }
+}
+
+#[test]
+fn test_reader_into_pyarrow_error() {
+    pyo3::prepare_freethreaded_python();
+
+    let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2]));
+    let batch = RecordBatch::try_from_iter(vec![("a", a)]).unwrap();
+    let schema = batch.schema();
+    let batches = vec![
+        Ok(batch.clone()),
+        Ok(batch),
+        Err(ArrowError::ComputeError(
+            "third batch is broken".to_string(),
+        )),
+    ];
+    let reader = RecordBatchIterator::new(batches, schema);
+
+    let err = Python::with_gil(|py| {
+        let py_reader = reader_into_pyarrow(py, reader)?;
+        let py_reader = py_reader.as_ref(py);
+        py_reader.call_method0("read_next_batch")?;
+        py_reader.call_method0("read_next_batch")?;
+        py_reader.call_method0("read_next_batch").map(|_| ())
+    })
+    .unwrap_err();
+
+    assert!(err.to_string().contains("third batch is broken"), "{err}");
+}
+
+#[test]
+fn test_vec_to_pyarrow() {
+    pyo3::prepare_freethreaded_python();
+
+    Python::with_gil(|py| {
+        // Vec<u8> is a single binary scalar
+        let value = vec![1_u8, 2].to_pyarrow(py)?;
+        assert_eq!(value.as_ref(py).getattr("type")?.to_string(), "binary");
+
+        // any other Vec<T> is a list of scalars
+        let values = vec![1_i32, 2].to_pyarrow(py)?;
+        let values = values.as_ref(py).downcast::<PyList>()?;
+        assert_eq!(values.len(), 2);
+        for value in values {
+            assert_eq!(value.getattr("type")?.to_string(), "int32");
+        }
+        Ok::<_, pyo3::PyErr>(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn test_pyarrow_scalar() {
+    pyo3::prepare_freethreaded_python();
+
+    let a: ArrayRef = Arc::new(Int32Array::from(vec![None]));
+    let b: ArrayRef = Arc::new(StringArray::from(vec!["b"]));
+    let scalars: Vec<ArrayRef> = vec![
+        Arc::new(Int32Array::from(vec![1])),
+        b.clone(),
+        a.clone(),
+        Arc::new(StructArray::from(vec![
+            (Arc::new(Field::new("a", DataType::Int32, true)), a),
+            (Arc::new(Field::new("b", DataType::Utf8, true)), b),
+        ])),
+    ];
+
+    for array in scalars {
+        let input = PyArrowScalar::try_new(array).unwrap();
+        let res = Python::with_gil(|py| {
+            let py_input = input.to_pyarrow(py)?;
+            PyArrowScalar::from_pyarrow(py_input.as_ref(py))
+        })
+        .unwrap();
+        assert_eq!(res.array(), input.array());
+    }
+
+    let array: ArrayRef = Arc::new(Int32Array::from(vec![1, 2]));
+    assert!(PyArrowScalar::try_new(array).is_err());
+}
+
+#[test]
+fn test_import_validation_level() {
+    pyo3::prepare_freethreaded_python();
+
+    // A single string that is not valid UTF-8
+    let data = unsafe {
+        ArrayData::builder(DataType::Utf8)
+            .len(1)
+            .add_buffer(Buffer::from_slice_ref([0_i32, 2]))
+            .add_buffer(Buffer::from_slice_ref([0xff_u8, 0xfe]))
+            .build_unchecked()
+    };
+
+    Python::with_gil(|py| {
+        let value = data.to_pyarrow(py).unwrap();
+        let value = value.as_ref(py);
+
+        assert!(ArrayData::from_pyarrow(value).is_err());
+        for (validation, ok) in [
+            (ValidationLevel::Full, false),
+            (ValidationLevel::Structural, true),
+            (ValidationLevel::None, true),
+        ] {
+            let options = unsafe { ImportOptions::default().with_validation(validation) };
+            let res = ArrayData::from_pyarrow_with_options(value, options);
+            assert_eq!(res.is_ok(), ok, "{validation:?}");
+        }
+    });
 }